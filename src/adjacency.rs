@@ -0,0 +1,130 @@
+/// An adjacency map, associating each node to the targets of its edges.
+/// Nodes are identified by their quoted `Display` output.
+impl<'a, N> crate::Labeller<'a> for std::collections::BTreeMap<N, Vec<N>>
+where
+    N: std::fmt::Display + Clone + Ord + 'a,
+{
+    type Node = N;
+    type Edge = (N, N);
+    type Subgraph = ();
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        crate::Id::new("G")
+    }
+
+    fn node_id(&'a self, n: &N) -> crate::Result<crate::Id<'a>> {
+        Ok(crate::Id::quoted(n.to_string()))
+    }
+}
+
+impl<'a, N> crate::GraphWalk<'a> for std::collections::BTreeMap<N, Vec<N>>
+where
+    N: std::fmt::Display + Clone + Ord + 'a,
+{
+    type Node = N;
+    type Edge = (N, N);
+    type Subgraph = ();
+
+    fn nodes(&'a self) -> crate::Nodes<'a, N> {
+        nodes(self.iter())
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, (N, N)> {
+        self.edges_iter().collect()
+    }
+
+    fn edges_iter(&'a self) -> Box<dyn Iterator<Item = (N, N)> + 'a> {
+        edges(self.iter())
+    }
+
+    fn source(&'a self, edge: &(N, N)) -> N {
+        edge.0.clone()
+    }
+
+    fn target(&'a self, edge: &(N, N)) -> N {
+        edge.1.clone()
+    }
+}
+
+/// An adjacency map, like `BTreeMap`. Nodes and edges are written sorted
+/// for a stable output.
+impl<'a, N, H> crate::Labeller<'a> for std::collections::HashMap<N, Vec<N>, H>
+where
+    N: std::fmt::Display + Clone + Ord + std::hash::Hash + 'a,
+    H: std::hash::BuildHasher,
+{
+    type Node = N;
+    type Edge = (N, N);
+    type Subgraph = ();
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        crate::Id::new("G")
+    }
+
+    fn node_id(&'a self, n: &N) -> crate::Result<crate::Id<'a>> {
+        Ok(crate::Id::quoted(n.to_string()))
+    }
+}
+
+impl<'a, N, H> crate::GraphWalk<'a> for std::collections::HashMap<N, Vec<N>, H>
+where
+    N: std::fmt::Display + Clone + Ord + std::hash::Hash + 'a,
+    H: std::hash::BuildHasher,
+{
+    type Node = N;
+    type Edge = (N, N);
+    type Subgraph = ();
+
+    fn nodes(&'a self) -> crate::Nodes<'a, N> {
+        nodes(self.iter())
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, (N, N)> {
+        self.edges_iter().collect()
+    }
+
+    fn edges_iter(&'a self) -> Box<dyn Iterator<Item = (N, N)> + 'a> {
+        edges(self.iter())
+    }
+
+    fn source(&'a self, edge: &(N, N)) -> N {
+        edge.0.clone()
+    }
+
+    fn target(&'a self, edge: &(N, N)) -> N {
+        edge.1.clone()
+    }
+}
+
+/// Sorted nodes of the map, targets without an entry included.
+fn nodes<'a, N, I>(entries: I) -> crate::Nodes<'a, N>
+where
+    N: Clone + Ord + 'a,
+    I: Iterator<Item = (&'a N, &'a Vec<N>)>,
+{
+    let mut nodes = entries
+        .flat_map(|(source, targets)| std::iter::once(source).chain(targets))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    nodes.sort();
+    nodes.dedup();
+
+    nodes.into()
+}
+
+/// Edges of the map, by source then in the targets order.
+fn edges<'a, N, I>(entries: I) -> Box<dyn Iterator<Item = (N, N)> + 'a>
+where
+    N: Clone + Ord + 'a,
+    I: Iterator<Item = (&'a N, &'a Vec<N>)>,
+{
+    let mut entries = entries.collect::<Vec<_>>();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+
+    Box::new(entries.into_iter().flat_map(|(source, targets)| {
+        targets
+            .iter()
+            .map(move |target| (source.clone(), target.clone()))
+    }))
+}
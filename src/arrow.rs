@@ -2,7 +2,8 @@
 /// as defined in [grapviz documentation](http://www.graphviz.org/content/arrow-shapes).
 #[derive(Clone, Copy, Hash, PartialEq, Eq)]
 pub enum Shape {
-    /// No arrow will be displayed
+    /// No arrow will be displayed. Within a multi-shape arrow, leaves a
+    /// gap the size of a shape, see `Shape::spacer`.
     NoArrow,
     /// Arrow that ends in a triangle. Basically a normal arrow.
     /// NOTE: there is error in official documentation, this supports both fill and side clipping
@@ -33,6 +34,12 @@ impl Shape {
         Self::NoArrow
     }
 
+    /// Constructor which returns a positional spacer, shifting the next
+    /// shapes of a multi-shape arrow away from the node.
+    pub fn spacer() -> Self {
+        Self::NoArrow
+    }
+
     /// Constructor which returns normal arrow.
     pub fn normal() -> Self {
         Self::Normal(crate::Fill::Filled, crate::Side::Both)
@@ -65,7 +72,7 @@ impl Shape {
 
     /// Constructor which returns a circle shaped arrow.
     pub fn dot() -> Self {
-        Self::Diamond(crate::Fill::Filled, crate::Side::Both)
+        Self::Dot(crate::Fill::Filled)
     }
 
     /// Constructor which returns an inverted triangle arrow.
@@ -82,6 +89,77 @@ impl Shape {
     pub fn vee() -> Self {
         Self::Vee(crate::Side::Both)
     }
+
+    /// The base shapes `parse` recognizes, see `all_names`.
+    const NAMES: &'static [&'static str] = &[
+        "none", "normal", "box", "crow", "curve", "icurve", "diamond", "dot", "inv", "tee", "vee",
+    ];
+
+    /// Returns the name of every base shape, without modifiers, as
+    /// written by graphviz.
+    pub fn all_names() -> &'static [&'static str] {
+        Self::NAMES
+    }
+
+    /// The base shape called `name`, with the `fill` and `side` modifiers
+    /// it supports.
+    fn named(name: &str, fill: crate::Fill, side: crate::Side) -> Option<Self> {
+        let shape = match name {
+            "none" => Self::NoArrow,
+            "normal" => Self::Normal(fill, side),
+            "box" => Self::Box(fill, side),
+            "crow" => Self::Crow(side),
+            "curve" => Self::Curve(side),
+            "icurve" => Self::ICurve(fill, side),
+            "diamond" => Self::Diamond(fill, side),
+            "dot" => Self::Dot(fill),
+            "inv" => Self::Inv(fill, side),
+            "tee" => Self::Tee(side),
+            "vee" => Self::Vee(side),
+            _ => return None,
+        };
+
+        Some(shape)
+    }
+
+    /// Parses the shape starting `s`, with its modifiers, returning it
+    /// with the rest of `s`.
+    fn parse(s: &str) -> Option<(Self, &str)> {
+        let (fill, s) = match s.strip_prefix('o') {
+            Some(s) => (crate::Fill::Open, s),
+            None => (crate::Fill::Filled, s),
+        };
+        let (side, s) = if let Some(s) = s.strip_prefix('l') {
+            (crate::Side::Left, s)
+        } else if let Some(s) = s.strip_prefix('r') {
+            (crate::Side::Right, s)
+        } else {
+            (crate::Side::Both, s)
+        };
+        let open = fill == crate::Fill::Open;
+        let clipped = side != crate::Side::Both;
+
+        for name in Self::NAMES {
+            let Some(rest) = s.strip_prefix(name) else {
+                continue;
+            };
+            let shape = Self::named(name, fill, side)?;
+
+            let fillable = !matches!(
+                shape,
+                Self::NoArrow | Self::Crow(_) | Self::Curve(_) | Self::Tee(_) | Self::Vee(_)
+            );
+            let sided = !matches!(shape, Self::NoArrow | Self::Dot(_));
+
+            if (open && !fillable) || (clipped && !sided) {
+                return None;
+            }
+
+            return Some((shape, rest));
+        }
+
+        None
+    }
 }
 
 impl std::fmt::Display for Shape {
@@ -160,6 +238,33 @@ impl Arrow {
             arrows: vec![arrow],
         }
     }
+
+    /// Arrow constructor which returns an arrow stacking the given shapes,
+    /// graphviz draws the first four of them.
+    #[must_use]
+    pub fn from_shapes<I: IntoIterator<Item = Shape>>(shapes: I) -> Self {
+        Self {
+            arrows: shapes.into_iter().collect(),
+        }
+    }
+
+    /// Checked arrow constructor, returning `Error::InvalidArrow` for
+    /// stacks graphviz rejects: more than four shapes. `Shape::spacer`
+    /// may come before an other shape.
+    pub fn try_new(arrows: Vec<Shape>) -> crate::Result<Self> {
+        let arrow = Self { arrows };
+
+        if arrow.arrows.len() > 4 {
+            return Err(crate::Error::InvalidArrow(arrow.to_string()));
+        }
+
+        Ok(arrow)
+    }
+
+    /// Stacks `shape` after the shapes of this arrow.
+    pub fn push(&mut self, shape: Shape) {
+        self.arrows.push(shape);
+    }
 }
 
 impl std::fmt::Display for Arrow {
@@ -172,6 +277,32 @@ impl std::fmt::Display for Arrow {
     }
 }
 
+/// Parses graphviz arrow syntax, up to four stacked shapes with their
+/// modifiers, like `"onormal"` or `"invdot"`. The stack is then checked
+/// by `Arrow::try_new`.
+impl std::str::FromStr for Arrow {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut arrows = Vec::new();
+        let mut rest = s;
+
+        while !rest.is_empty() {
+            let (shape, tail) =
+                Shape::parse(rest).ok_or_else(|| crate::Error::InvalidArrow(s.to_string()))?;
+
+            arrows.push(shape);
+            rest = tail;
+        }
+
+        if arrows.is_empty() {
+            return Err(crate::Error::InvalidArrow(s.to_string()));
+        }
+
+        Self::try_new(arrows)
+    }
+}
+
 impl From<[Shape; 2]> for Arrow {
     fn from(shape: [Shape; 2]) -> Self {
         Self {
@@ -0,0 +1,108 @@
+/// A color, as written in DOT.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Color {
+    /// A RGB color, written in hexadecimal: `#rrggbb`.
+    Rgb(u8, u8, u8),
+    /// The color at this index, from 1, of the active `colorscheme`, like
+    /// a Brewer palette.
+    Scheme(u8),
+}
+
+impl Color {
+    #[must_use]
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self::Rgb(r, g, b)
+    }
+
+    /// Parses `#rrggbb`, `#rrggbbaa` (ignoring the alpha channel), one of
+    /// the basic color names with its graphviz X11 value, or a color
+    /// scheme index.
+    #[must_use]
+    pub fn parse(s: &str) -> std::option::Option<Self> {
+        if let Some(hex) = s.strip_prefix('#') {
+            if !matches!(hex.len(), 6 | 8) || !hex.is_ascii() {
+                return None;
+            }
+
+            let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+
+            return Some(Self::new(channel(0)?, channel(2)?, channel(4)?));
+        }
+
+        if let Ok(index) = s.parse() {
+            // Color scheme indices start at 1.
+            return (index != 0).then_some(Self::Scheme(index));
+        }
+
+        let color = match s.to_ascii_lowercase().as_str() {
+            "black" => Self::new(0, 0, 0),
+            "white" => Self::new(255, 255, 255),
+            "gray" | "grey" => Self::new(190, 190, 190),
+            "red" => Self::new(255, 0, 0),
+            "green" => Self::new(0, 255, 0),
+            "blue" => Self::new(0, 0, 255),
+            "yellow" => Self::new(255, 255, 0),
+            "cyan" => Self::new(0, 255, 255),
+            "magenta" => Self::new(255, 0, 255),
+            "navy" => Self::new(0, 0, 128),
+            "maroon" => Self::new(176, 48, 96),
+            "purple" => Self::new(160, 32, 240),
+            "darkblue" => Self::new(0, 0, 139),
+            "darkgreen" => Self::new(0, 100, 0),
+            "darkred" => Self::new(139, 0, 0),
+            _ => return None,
+        };
+
+        Some(color)
+    }
+
+    /// The relative luminance, from `0.` for black to `1.` for white.
+    /// Unknown for a color scheme index.
+    #[must_use]
+    pub fn luminance(&self) -> std::option::Option<f64> {
+        let Self::Rgb(r, g, b) = *self else {
+            return None;
+        };
+
+        let linear = |c: u8| {
+            let c = f64::from(c) / 255.;
+
+            if c <= 0.039_28 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+    }
+
+    /// Mixes the color with white, `amount` going from `0.` (unchanged)
+    /// to `1.` (white). A color scheme index is left unchanged.
+    #[must_use]
+    pub fn lighten(&self, amount: f64) -> Self {
+        let Self::Rgb(r, g, b) = *self else {
+            return *self;
+        };
+
+        let mix =
+            |c: u8| (f64::from(c) + (255. - f64::from(c)) * amount.clamp(0., 1.)).round() as u8;
+
+        Self::new(mix(r), mix(g), mix(b))
+    }
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rgb(r, g, b) => write!(f, "#{r:02x}{g:02x}{b:02x}"),
+            Self::Scheme(index) => write!(f, "{index}"),
+        }
+    }
+}
+
+impl<'a> From<Color> for crate::label::Text<'a> {
+    fn from(color: Color) -> Self {
+        Self::LabelStr(color.to_string().into())
+    }
+}
@@ -0,0 +1,36 @@
+/// Where an edge attaches to a node, see
+/// <https://www.graphviz.org/docs/attr-types/portPos/>.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Compass {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+    /// The center of the node.
+    C,
+    /// Anywhere on the node, `_`.
+    Any,
+}
+
+impl std::fmt::Display for Compass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::N => "n",
+            Self::NE => "ne",
+            Self::E => "e",
+            Self::SE => "se",
+            Self::S => "s",
+            Self::SW => "sw",
+            Self::W => "w",
+            Self::NW => "nw",
+            Self::C => "c",
+            Self::Any => "_",
+        };
+
+        write!(f, "{s}")
+    }
+}
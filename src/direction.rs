@@ -0,0 +1,26 @@
+/// The ends of an edge where arrows are drawn, see
+/// <https://www.graphviz.org/docs/attrs/dir/>.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Direction {
+    /// An arrow at the head, the default of directed graphs.
+    Forward,
+    /// An arrow at the tail.
+    Back,
+    /// Arrows at both ends.
+    Both,
+    /// No arrow, a plain line.
+    None,
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Forward => "forward",
+            Self::Back => "back",
+            Self::Both => "both",
+            Self::None => "none",
+        };
+
+        write!(f, "{s}")
+    }
+}
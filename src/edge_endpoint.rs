@@ -0,0 +1,7 @@
+/// One end of an edge: either a node, or a whole subgraph, in which case
+/// graphviz draws the edge from or to every node of the subgraph.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EdgeEndpoint<N, S> {
+    Node(N),
+    Subgraph(S),
+}
@@ -4,6 +4,11 @@ pub type Result<T = ()> = std::result::Result<T, Error>;
 pub enum Error {
     Io(std::io::Error),
     InvalidId,
+    UnbalancedHtml,
+    InvalidEdgeStyle(crate::Style),
+    IncompatibleHtmlShape(String),
+    IncompatiblePolygonShape(String),
+    InvalidArrow(String),
 }
 
 impl std::error::Error for Error {}
@@ -12,6 +17,15 @@ impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
             Self::InvalidId => "Invalid id".to_string(),
+            Self::UnbalancedHtml => "Unbalanced HTML".to_string(),
+            Self::InvalidEdgeStyle(style) => format!("Invalid edge style: {style}"),
+            Self::IncompatibleHtmlShape(shape) => {
+                format!("HTML label incompatible with shape: {shape}")
+            }
+            Self::IncompatiblePolygonShape(shape) => {
+                format!("Polygon attributes incompatible with shape: {shape}")
+            }
+            Self::InvalidArrow(arrow) => format!("Invalid arrow: {arrow}"),
             Self::Io(err) => format!("{err}"),
         };
 
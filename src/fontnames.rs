@@ -0,0 +1,20 @@
+/// How font names are written in SVG output, see
+/// <https://www.graphviz.org/docs/attrs/fontnames/>.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Fontnames {
+    Svg,
+    Ps,
+    Gd,
+}
+
+impl std::fmt::Display for Fontnames {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Svg => "svg",
+            Self::Ps => "ps",
+            Self::Gd => "gd",
+        };
+
+        write!(f, "{s}")
+    }
+}
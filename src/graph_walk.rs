@@ -18,20 +18,109 @@ pub trait GraphWalk<'a> {
 
     /// Returns all the nodes in this graph.
     fn nodes(&'a self) -> crate::Nodes<'a, Self::Node>;
+    /// Returns `true` if this graph has no node, edges still creating
+    /// theirs implicitly. Defaults to checking `nodes`.
+    fn is_empty(&'a self) -> bool {
+        self.nodes().is_empty()
+    }
+
     /// Returns all of the edges in this graph.
     fn edges(&'a self) -> crate::Edges<'a, Self::Edge>;
+    /// Iterates over all of the edges in this graph, this is what the
+    /// renderer uses. Defaults to iterating `edges`, cloning each edge of
+    /// a borrowed slice as it goes.
+    ///
+    /// Override it too when the edges can't be returned as a single slice
+    /// without cloning them, from adjacency lists for example: `edges`
+    /// can then collect it.
+    fn edges_iter(&'a self) -> Box<dyn Iterator<Item = Self::Edge> + 'a> {
+        match self.edges() {
+            std::borrow::Cow::Borrowed(edges) => Box::new(edges.iter().cloned()),
+            std::borrow::Cow::Owned(edges) => Box::new(edges.into_iter()),
+        }
+    }
+
     /// The source node for `edge`.
     fn source(&'a self, edge: &Self::Edge) -> Self::Node;
     /// The target node for `edge`.
     fn target(&'a self, edge: &Self::Edge) -> Self::Node;
 
+    /// The source endpoint for `edge`, defaults to its `source` node. An
+    /// error aborts the rendering.
+    fn source_endpoint(
+        &'a self,
+        edge: &Self::Edge,
+    ) -> crate::Result<crate::EdgeEndpoint<Self::Node, Self::Subgraph>> {
+        Ok(crate::EdgeEndpoint::Node(self.source(edge)))
+    }
+
+    /// The target endpoint for `edge`, defaults to its `target` node. An
+    /// error aborts the rendering.
+    fn target_endpoint(
+        &'a self,
+        edge: &Self::Edge,
+    ) -> crate::Result<crate::EdgeEndpoint<Self::Node, Self::Subgraph>> {
+        Ok(crate::EdgeEndpoint::Node(self.target(edge)))
+    }
+
     /// Retuns all the subgraphs in this graph.
     fn subgraphs(&'a self) -> crate::Subgraphs<'a, Self::Subgraph> {
         std::borrow::Cow::Borrowed(&[])
     }
 
-    /// Retuns all the subgraphs in this graph.
+    /// Retuns all the nodes in `s`.
     fn subgraph_nodes(&'a self, _s: &Self::Subgraph) -> crate::Nodes<'a, Self::Node> {
         std::borrow::Cow::Borrowed(&[])
     }
+
+    /// Returns the edges rendered inside `s`. They must not be returned
+    /// by `edges` too, or they would be rendered twice.
+    fn subgraph_edges(&'a self, _s: &Self::Subgraph) -> crate::Edges<'a, Self::Edge> {
+        std::borrow::Cow::Borrowed(&[])
+    }
+
+    /// Returns the subgraphs nested in `s`, rendered inside it. Only
+    /// top level subgraphs should be returned by `subgraphs`.
+    fn subgraph_children(&'a self, _s: &Self::Subgraph) -> crate::Subgraphs<'a, Self::Subgraph> {
+        std::borrow::Cow::Borrowed(&[])
+    }
+
+    /// Returns the rank constraint on the nodes of `s`. A subgraph
+    /// without `Labeller::subgraph_id` is then rendered as an anonymous
+    /// `{ rank=same; ... }` group. Defaults to no constraint.
+    fn subgraph_rank(&'a self, _s: &Self::Subgraph) -> Option<crate::Rank> {
+        None
+    }
+
+    /// Returns nodes of `s` to lay out on the same rank, in this order
+    /// from left to right, which is enforced by chaining them with
+    /// invisible edges. Defaults to no ordering.
+    fn rank_order(&'a self, _s: &Self::Subgraph) -> crate::Nodes<'a, Self::Node> {
+        std::borrow::Cow::Borrowed(&[])
+    }
+
+    /// Returns the level of `n` in a layered graph: nodes of the same
+    /// level are laid out on the same rank. If `None` is returned, `n`
+    /// is left to the layout.
+    fn node_rank(&'a self, _n: &Self::Node) -> Option<i32> {
+        None
+    }
+}
+
+/// Iterates over the edges of `g` from `GraphWalk::edges_iter`, then over
+/// the `GraphWalk::subgraph_edges` of every subgraph, nested ones included.
+pub(crate) fn all_edges<'a, G: GraphWalk<'a>>(g: &'a G) -> impl Iterator<Item = G::Edge> + 'a {
+    let mut subgraphs = g.subgraphs().into_owned();
+    let mut i = 0;
+    while i < subgraphs.len() {
+        let children = g.subgraph_children(&subgraphs[i]);
+        subgraphs.extend(children.iter().cloned());
+        i += 1;
+    }
+
+    g.edges_iter().chain(
+        subgraphs
+            .into_iter()
+            .flat_map(move |s| g.subgraph_edges(&s).into_owned()),
+    )
 }
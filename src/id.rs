@@ -1,6 +1,19 @@
+/// How the name of an `Id` is validated and written.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum IdMode {
+    /// A bare identifier, `[a-zA-Z_][a-zA-Z_0-9]*`.
+    Strict,
+    /// Any text, written between double quotes.
+    Quoted,
+    /// An HTML string, written between `<` and `>`.
+    Html,
+}
+
 /// `Id` is a Graphviz `ID`.
 pub struct Id<'a> {
     pub(crate) name: std::borrow::Cow<'a, str>,
+    pub(crate) mode: IdMode,
+    pub(crate) port: Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)>,
 }
 
 impl<'a> Id<'a> {
@@ -9,7 +22,8 @@ impl<'a> Id<'a> {
     /// The caller must ensure that the input conforms to an
     /// identifier format: it must be a non-empty string made up of
     /// alphanumeric or underscore characters, not beginning with a
-    /// digit (i.e., the regular expression `[a-zA-Z_][a-zA-Z_0-9]*`).
+    /// digit (i.e., the regular expression `[a-zA-Z_][a-zA-Z_0-9]*`),
+    /// or a numeral (`[-]?(.[0-9]+|[0-9]+(.[0-9]*)?)`, like `3.14`).
     ///
     /// (Note: this format is a strict subset of the `ID` format
     /// defined by the DOT language. This function may change in the
@@ -19,23 +33,170 @@ impl<'a> Id<'a> {
     /// Passing an invalid string (containing spaces, brackets,
     /// quotes, ...) will return an empty `Err` value.
     pub fn new<Name: Into<std::borrow::Cow<'a, str>>>(name: Name) -> crate::Result<Self> {
+        Self::new_validated(name, IdMode::Strict)
+    }
+
+    /// Creates an `Id` named `name`, validated according to `mode`:
+    ///
+    /// * `IdMode::Strict` accepts the same names as `new`, else returns
+    ///   `Error::InvalidId`;
+    /// * `IdMode::Quoted` accepts any name, its quotes and backslashes
+    ///   are escaped on output;
+    /// * `IdMode::Html` accepts names whose `<` and `>` are balanced,
+    ///   else returns `Error::UnbalancedHtml`.
+    pub fn new_validated<Name: Into<std::borrow::Cow<'a, str>>>(
+        name: Name,
+        mode: IdMode,
+    ) -> crate::Result<Self> {
         let name = name.into();
 
-        match name.chars().next() {
-            Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
-            _ => return Err(crate::Error::InvalidId),
+        match mode {
+            IdMode::Strict => {
+                if !is_identifier(&name) && !is_numeral(&name) {
+                    return Err(crate::Error::InvalidId);
+                }
+            }
+            IdMode::Quoted => {}
+            IdMode::Html => {
+                let mut depth = 0usize;
+
+                for c in name.chars() {
+                    match c {
+                        '<' => depth += 1,
+                        '>' => depth = depth.checked_sub(1).ok_or(crate::Error::UnbalancedHtml)?,
+                        _ => {}
+                    }
+                }
+
+                if depth != 0 {
+                    return Err(crate::Error::UnbalancedHtml);
+                }
+            }
         }
 
-        if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
-            return Err(crate::Error::InvalidId);
+        Ok(Self {
+            name,
+            mode,
+            port: None,
+        })
+    }
+
+    /// Creates an `Id` named `name`, written between double quotes so
+    /// any text is accepted, like `IdMode::Quoted`.
+    #[must_use]
+    pub fn quoted<Name: Into<std::borrow::Cow<'a, str>>>(name: Name) -> Self {
+        Self {
+            name: name.into(),
+            mode: IdMode::Quoted,
+            port: None,
         }
+    }
 
-        Ok(Self { name })
+    /// Creates an `Id` named `name`, written as is if `new` accepts it,
+    /// else between double quotes like `quoted`. Useful for names given
+    /// by users, like `2024-report`, as `Labeller::graph_id`.
+    #[must_use]
+    pub fn auto_quoted<Name: Into<std::borrow::Cow<'a, str>>>(name: Name) -> Self {
+        let name = name.into();
+
+        if is_identifier(&name) || is_numeral(&name) {
+            Self {
+                name,
+                mode: IdMode::Strict,
+                port: None,
+            }
+        } else {
+            Self::quoted(name)
+        }
+    }
+
+    /// The name of the `Id`, as given, without quotes, escapes or port.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.name
     }
+
+    /// Qualifies the `Id` of a node with one of its ports, a field of a
+    /// record for example, and an optional compass point: an edge between
+    /// such identifiers attaches there, like `node0:f0:nw`.
+    #[must_use]
+    pub fn with_port<Port: Into<std::borrow::Cow<'a, str>>>(
+        self,
+        port: Port,
+        compass: Option<crate::Compass>,
+    ) -> Self {
+        Self {
+            port: Some((port.into(), compass)),
+            ..self
+        }
+    }
+
+    /// Converts into an `Id` owning its name, cloning it if borrowed.
+    #[must_use]
+    pub fn into_owned(self) -> Id<'static> {
+        Id {
+            name: self.name.into_owned().into(),
+            mode: self.mode,
+            port: self
+                .port
+                .map(|(port, compass)| (port.into_owned().into(), compass)),
+        }
+    }
+}
+
+/// Matches `[a-zA-Z_][a-zA-Z_0-9]*`.
+fn is_identifier(name: &str) -> bool {
+    match name.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+
+    name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Matches the DOT numeral grammar, `[-]?(.[0-9]+|[0-9]+(.[0-9]*)?)`.
+fn is_numeral(name: &str) -> bool {
+    let name = name.strip_prefix('-').unwrap_or(name);
+
+    let (integer, fraction) = match name.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (name, None),
+    };
+
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+
+    if !is_digits(integer) || !fraction.is_none_or(is_digits) {
+        return false;
+    }
+
+    // `.` alone or `-` alone doesn't hold any digit
+    !integer.is_empty() || fraction.is_some_and(|fraction| !fraction.is_empty())
 }
 
 impl<'a> std::fmt::Display for Id<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.name)
+        match self.mode {
+            IdMode::Strict => write!(f, "{}", self.name),
+            IdMode::Quoted => {
+                let name = self.name.replace('\\', r"\\").replace('"', "\\\"");
+
+                write!(f, "\"{name}\"")
+            }
+            IdMode::Html => write!(f, "<{}>", self.name),
+        }?;
+
+        if let Some((port, compass)) = &self.port {
+            if is_identifier(port) {
+                write!(f, ":{port}")?;
+            } else {
+                write!(f, ":{}", Id::quoted(port.as_ref()))?;
+            }
+
+            if let Some(compass) = compass {
+                write!(f, ":{compass}")?;
+            }
+        }
+
+        Ok(())
     }
 }
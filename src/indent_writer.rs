@@ -0,0 +1,53 @@
+/// Wraps a writer to prefix each line with the current indentation, so
+/// that renderers only write their content. Empty lines stay empty.
+pub(crate) struct IndentWriter<'w, W: std::io::Write> {
+    inner: &'w mut W,
+    depth: usize,
+    line_start: bool,
+}
+
+impl<'w, W: std::io::Write> IndentWriter<'w, W> {
+    pub(crate) fn new(inner: &'w mut W) -> Self {
+        Self {
+            inner,
+            depth: 0,
+            line_start: true,
+        }
+    }
+
+    /// Indents the next lines one more level.
+    pub(crate) fn indent(&mut self) {
+        self.depth += 1;
+    }
+
+    /// Indents the next lines one less level.
+    pub(crate) fn dedent(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+
+    /// Lets `write!` work without importing `std::io::Write`.
+    pub(crate) fn write_fmt(&mut self, args: std::fmt::Arguments<'_>) -> std::io::Result<()> {
+        std::io::Write::write_fmt(self, args)
+    }
+}
+
+impl<'w, W: std::io::Write> std::io::Write for IndentWriter<'w, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        for line in buf.split_inclusive(|c| *c == b'\n') {
+            if self.line_start && line != b"\n" {
+                for _ in 0..self.depth {
+                    self.inner.write_all(b"    ")?;
+                }
+            }
+
+            self.inner.write_all(line)?;
+            self.line_start = line.ends_with(b"\n");
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
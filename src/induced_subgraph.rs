@@ -0,0 +1,428 @@
+/// A view of `G` restricted to the nodes matching a predicate, the edges
+/// between them and the subgraphs left with some of them, to render a
+/// slice of a large graph.
+pub struct InducedSubgraph<'g, G, P> {
+    graph: &'g G,
+    predicate: P,
+}
+
+impl<'g, G, P> InducedSubgraph<'g, G, P>
+where
+    G: crate::GraphWalk<'g>,
+    P: Fn(&G::Node) -> bool,
+{
+    /// Creates a view of `graph` keeping the nodes for which `predicate`
+    /// returns `true`.
+    pub fn new(graph: &'g G, predicate: P) -> Self {
+        Self { graph, predicate }
+    }
+
+    fn filter<'a>(&self, nodes: &[G::Node]) -> crate::Nodes<'a, G::Node> {
+        nodes
+            .iter()
+            .filter(|n| (self.predicate)(n))
+            .cloned()
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Keeps the subgraphs with a node matching the predicate, directly
+    /// or in one of their children.
+    fn filter_subgraphs<'a>(&self, subgraphs: &[G::Subgraph]) -> crate::Subgraphs<'a, G::Subgraph> {
+        subgraphs
+            .iter()
+            .filter(|s| self.has_nodes(s))
+            .cloned()
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn has_nodes(&self, s: &G::Subgraph) -> bool {
+        self.graph
+            .subgraph_nodes(s)
+            .iter()
+            .any(|n| (self.predicate)(n))
+            || self
+                .graph
+                .subgraph_children(s)
+                .iter()
+                .any(|child| self.has_nodes(child))
+    }
+}
+
+impl<'a, 'g: 'a, G, P> crate::Labeller<'a> for InducedSubgraph<'g, G, P>
+where
+    G: crate::Labeller<'g>,
+{
+    type Node = G::Node;
+    type Edge = G::Edge;
+    type Subgraph = G::Subgraph;
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        self.graph.graph_id()
+    }
+
+    fn graph_label(&'a self) -> Option<crate::label::Text<'a>> {
+        self.graph.graph_label()
+    }
+
+    fn graph_labelloc(&'a self) -> Option<char> {
+        self.graph.graph_labelloc()
+    }
+
+    fn graph_colorscheme(&'a self) -> Option<crate::label::Text<'a>> {
+        self.graph.graph_colorscheme()
+    }
+
+    fn graph_attributes(&'a self) -> Vec<(String, crate::label::Text<'a>)> {
+        self.graph.graph_attributes()
+    }
+
+    fn graph_svg_id(&'a self) -> Option<String> {
+        self.graph.graph_svg_id()
+    }
+
+    fn graph_fontsize(&'a self) -> Option<f64> {
+        self.graph.graph_fontsize()
+    }
+
+    fn graph_comment(&'a self) -> Option<crate::label::Text<'a>> {
+        self.graph.graph_comment()
+    }
+
+    fn node_defaults(&'a self) -> Vec<(String, crate::label::Text<'a>)> {
+        self.graph.node_defaults()
+    }
+
+    fn edge_defaults(&'a self) -> Vec<(String, crate::label::Text<'a>)> {
+        self.graph.edge_defaults()
+    }
+
+    fn node_id(&'a self, n: &Self::Node) -> crate::Result<crate::Id<'a>> {
+        self.graph.node_id(n)
+    }
+
+    fn node_shape(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.graph.node_shape(node)
+    }
+
+    fn node_label(&'a self, n: &Self::Node) -> crate::Result<crate::label::Text<'a>> {
+        self.graph.node_label(n)
+    }
+
+    fn node_xlabel(&'a self, n: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.graph.node_xlabel(n)
+    }
+
+    fn node_style(&'a self, n: &Self::Node) -> crate::Style {
+        self.graph.node_style(n)
+    }
+
+    fn node_styles(&'a self, n: &Self::Node) -> crate::StyleSet {
+        self.graph.node_styles(n)
+    }
+
+    fn node_colorscheme(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.graph.node_colorscheme(node)
+    }
+
+    fn node_color(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.graph.node_color(node)
+    }
+
+    fn node_fillcolor(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.graph.node_fillcolor(node)
+    }
+
+    fn node_url(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.graph.node_url(node)
+    }
+
+    fn node_target(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.graph.node_target(node)
+    }
+
+    fn node_comment(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.graph.node_comment(node)
+    }
+
+    fn node_skew(&'a self, node: &Self::Node) -> Option<f64> {
+        self.graph.node_skew(node)
+    }
+
+    fn node_distortion(&'a self, node: &Self::Node) -> Option<f64> {
+        self.graph.node_distortion(node)
+    }
+
+    fn node_penwidth(&'a self, node: &Self::Node) -> Option<f64> {
+        self.graph.node_penwidth(node)
+    }
+
+    fn node_fontsize(&'a self, node: &Self::Node) -> Option<f64> {
+        self.graph.node_fontsize(node)
+    }
+
+    fn node_fontcolor(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.graph.node_fontcolor(node)
+    }
+
+    fn node_pin(&'a self, node: &Self::Node) -> bool {
+        self.graph.node_pin(node)
+    }
+
+    fn node_attributes(&'a self, node: &Self::Node) -> Vec<(String, crate::label::Text<'a>)> {
+        self.graph.node_attributes(node)
+    }
+
+    fn edge_end_arrow(&'a self, e: &Self::Edge) -> crate::Arrow {
+        self.graph.edge_end_arrow(e)
+    }
+
+    fn edge_start_arrow(&'a self, e: &Self::Edge) -> crate::Arrow {
+        self.graph.edge_start_arrow(e)
+    }
+
+    fn edge_dir(&'a self, e: &Self::Edge) -> Option<crate::Direction> {
+        self.graph.edge_dir(e)
+    }
+
+    fn edge_arrowsize(&'a self, e: &Self::Edge) -> Option<f64> {
+        self.graph.edge_arrowsize(e)
+    }
+
+    fn edge_label(&'a self, e: &Self::Edge) -> crate::label::Text<'a> {
+        self.graph.edge_label(e)
+    }
+
+    fn edge_xlabel(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_xlabel(e)
+    }
+
+    fn edge_headlabel(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_headlabel(e)
+    }
+
+    fn edge_taillabel(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_taillabel(e)
+    }
+
+    fn edge_headlabel_color(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_headlabel_color(e)
+    }
+
+    fn edge_taillabel_color(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_taillabel_color(e)
+    }
+
+    fn edge_labelfontname(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_labelfontname(e)
+    }
+
+    fn edge_labelfontsize(&'a self, e: &Self::Edge) -> Option<f64> {
+        self.graph.edge_labelfontsize(e)
+    }
+
+    fn edge_labelfontcolor(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_labelfontcolor(e)
+    }
+
+    fn edge_style(&'a self, e: &Self::Edge) -> crate::Style {
+        self.graph.edge_style(e)
+    }
+
+    fn edge_styles(&'a self, e: &Self::Edge) -> crate::StyleSet {
+        self.graph.edge_styles(e)
+    }
+
+    fn edge_color(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_color(e)
+    }
+
+    fn edge_url(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_url(e)
+    }
+
+    fn edge_target(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_target(e)
+    }
+
+    fn edge_comment(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_comment(e)
+    }
+
+    fn edge_tooltip(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_tooltip(e)
+    }
+
+    fn edge_labeltooltip(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_labeltooltip(e)
+    }
+
+    fn edge_headtooltip(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_headtooltip(e)
+    }
+
+    fn edge_tailtooltip(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_tailtooltip(e)
+    }
+
+    fn edge_attributes(&'a self, e: &Self::Edge) -> Vec<(String, crate::label::Text<'a>)> {
+        self.graph.edge_attributes(e)
+    }
+
+    fn edge_weight(&'a self, e: &Self::Edge) -> Option<f64> {
+        self.graph.edge_weight(e)
+    }
+
+    fn edge_weight_int(&'a self, e: &Self::Edge) -> Option<i64> {
+        self.graph.edge_weight_int(e)
+    }
+
+    fn edge_constraint(&'a self, e: &Self::Edge) -> bool {
+        self.graph.edge_constraint(e)
+    }
+
+    fn edge_len(&'a self, e: &Self::Edge) -> Option<f64> {
+        self.graph.edge_len(e)
+    }
+
+    fn edge_fontsize(&'a self, e: &Self::Edge) -> Option<f64> {
+        self.graph.edge_fontsize(e)
+    }
+
+    fn edge_fontcolor(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.graph.edge_fontcolor(e)
+    }
+
+    fn edge_penwidth(&'a self, e: &Self::Edge) -> Option<f64> {
+        self.graph.edge_penwidth(e)
+    }
+
+    fn edge_source_port(
+        &'a self,
+        e: &Self::Edge,
+    ) -> Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)> {
+        self.graph.edge_source_port(e)
+    }
+
+    fn edge_target_port(
+        &'a self,
+        e: &Self::Edge,
+    ) -> Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)> {
+        self.graph.edge_target_port(e)
+    }
+
+    fn subgraph_id(&'a self, s: &Self::Subgraph) -> Option<crate::Id<'a>> {
+        self.graph.subgraph_id(s)
+    }
+
+    fn subgraph_label(&'a self, s: &Self::Subgraph) -> crate::label::Text<'a> {
+        self.graph.subgraph_label(s)
+    }
+
+    fn subgraph_style(&'a self, s: &Self::Subgraph) -> crate::Style {
+        self.graph.subgraph_style(s)
+    }
+
+    fn subgraph_shape(&'a self, s: &Self::Subgraph) -> Option<crate::label::Text<'a>> {
+        self.graph.subgraph_shape(s)
+    }
+
+    fn subgraph_color(&'a self, s: &Self::Subgraph) -> Option<crate::label::Text<'a>> {
+        self.graph.subgraph_color(s)
+    }
+
+    fn subgraph_fontsize(&'a self, s: &Self::Subgraph) -> Option<f64> {
+        self.graph.subgraph_fontsize(s)
+    }
+
+    fn kind(&self) -> crate::Kind {
+        self.graph.kind()
+    }
+
+    fn strict(&self) -> bool {
+        self.graph.strict()
+    }
+}
+
+impl<'a, 'g: 'a, G, P> crate::GraphWalk<'a> for InducedSubgraph<'g, G, P>
+where
+    G: crate::GraphWalk<'g>,
+    P: Fn(&G::Node) -> bool,
+{
+    type Node = G::Node;
+    type Edge = G::Edge;
+    type Subgraph = G::Subgraph;
+
+    fn nodes(&'a self) -> crate::Nodes<'a, Self::Node> {
+        self.filter(&self.graph.nodes())
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, Self::Edge> {
+        self.edges_iter().collect()
+    }
+
+    fn edges_iter(&'a self) -> Box<dyn Iterator<Item = Self::Edge> + 'a> {
+        Box::new(self.graph.edges_iter().filter(|e| {
+            (self.predicate)(&self.graph.source(e)) && (self.predicate)(&self.graph.target(e))
+        }))
+    }
+
+    fn source(&'a self, edge: &Self::Edge) -> Self::Node {
+        self.graph.source(edge)
+    }
+
+    fn target(&'a self, edge: &Self::Edge) -> Self::Node {
+        self.graph.target(edge)
+    }
+
+    fn source_endpoint(
+        &'a self,
+        edge: &Self::Edge,
+    ) -> crate::Result<crate::EdgeEndpoint<Self::Node, Self::Subgraph>> {
+        self.graph.source_endpoint(edge)
+    }
+
+    fn target_endpoint(
+        &'a self,
+        edge: &Self::Edge,
+    ) -> crate::Result<crate::EdgeEndpoint<Self::Node, Self::Subgraph>> {
+        self.graph.target_endpoint(edge)
+    }
+
+    fn subgraphs(&'a self) -> crate::Subgraphs<'a, Self::Subgraph> {
+        self.filter_subgraphs(&self.graph.subgraphs())
+    }
+
+    fn subgraph_nodes(&'a self, s: &Self::Subgraph) -> crate::Nodes<'a, Self::Node> {
+        self.filter(&self.graph.subgraph_nodes(s))
+    }
+
+    fn subgraph_edges(&'a self, s: &Self::Subgraph) -> crate::Edges<'a, Self::Edge> {
+        self.graph
+            .subgraph_edges(s)
+            .iter()
+            .filter(|e| {
+                (self.predicate)(&self.graph.source(e)) && (self.predicate)(&self.graph.target(e))
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn subgraph_children(&'a self, s: &Self::Subgraph) -> crate::Subgraphs<'a, Self::Subgraph> {
+        self.filter_subgraphs(&self.graph.subgraph_children(s))
+    }
+
+    fn subgraph_rank(&'a self, s: &Self::Subgraph) -> Option<crate::Rank> {
+        self.graph.subgraph_rank(s)
+    }
+
+    fn rank_order(&'a self, s: &Self::Subgraph) -> crate::Nodes<'a, Self::Node> {
+        self.filter(&self.graph.rank_order(s))
+    }
+
+    fn node_rank(&'a self, n: &Self::Node) -> Option<i32> {
+        self.graph.node_rank(n)
+    }
+}
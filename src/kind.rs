@@ -7,6 +7,12 @@ pub enum Kind {
 }
 
 impl Kind {
+    /// Return `true` for `Kind::Digraph`.
+    #[must_use]
+    pub fn is_directed(self) -> bool {
+        self == Self::Digraph
+    }
+
     /// The edgeop syntax to use for this graph kind.
     pub(crate) fn edgeop(self) -> &'static str {
         match self {
@@ -47,6 +47,63 @@ pub trait Labeller<'a> {
     /// Must return a DOT compatible identifier naming the graph.
     fn graph_id(&'a self) -> crate::Result<crate::Id<'a>>;
 
+    /// The label of the graph, its title. If `None` is returned, no `label`
+    /// attribute is specified.
+    fn graph_label(&'a self) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Where the graph label is placed: `'t'` for the top, `'b'` for the
+    /// bottom. If `None` is returned, no `labelloc` attribute is
+    /// specified.
+    fn graph_labelloc(&'a self) -> Option<char> {
+        None
+    }
+
+    /// The color scheme, like the `set19` Brewer palette, in which
+    /// `Color::Scheme` indexes of the graph, nodes and edges are looked
+    /// up. If `None` is returned, no `colorscheme` attribute is specified.
+    fn graph_colorscheme(&'a self) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Additional attributes of the graph, written as is after the ones
+    /// from render options. Useful to pass through layout attributes like
+    /// `bb`.
+    fn graph_attributes(&'a self) -> Vec<(String, Text<'a>)> {
+        Vec::new()
+    }
+
+    /// Maps the graph to the `id` of its root element in SVG output,
+    /// telling apart several graphs embedded in the same page. If `None`
+    /// is returned, no `id` attribute is specified.
+    fn graph_svg_id(&'a self) -> Option<String> {
+        None
+    }
+
+    /// The font size of the graph label, in points. If `None` is returned,
+    /// no `fontsize` attribute is specified.
+    fn graph_fontsize(&'a self) -> Option<f64> {
+        None
+    }
+
+    /// The `comment` of the graph, like `node_comment`.
+    fn graph_comment(&'a self) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Default attributes of all nodes, written in the `node` statement
+    /// after the ones from render options.
+    fn node_defaults(&'a self) -> Vec<(String, Text<'a>)> {
+        Vec::new()
+    }
+
+    /// Default attributes of all edges, written in the `edge` statement
+    /// after the ones from render options.
+    fn edge_defaults(&'a self) -> Vec<(String, Text<'a>)> {
+        Vec::new()
+    }
+
     /// Maps `n` to a unique identifier with respect to `self`. The
     /// implementor is responsible for ensuring that the returned name
     /// is a valid DOT identifier.
@@ -55,6 +112,9 @@ pub trait Labeller<'a> {
     /// Maps `n` to one of the [graphviz `shape` names][1]. If `None`
     /// is returned, no `shape` attribute is specified.
     ///
+    /// `record` and `Mrecord`, its version with rounded corners, split
+    /// the node according to its label, like `"{a|<port> b}"`.
+    ///
     /// [1]: https://www.graphviz.org/content/node-shapes
     fn node_shape(&'a self, _node: &Self::Node) -> Option<Text<'a>> {
         None
@@ -67,11 +127,33 @@ pub trait Labeller<'a> {
         self.node_id(n).map(|x| Text::LabelStr(x.name))
     }
 
+    /// Maps `n` to an external label, placed near the node without
+    /// affecting the layout. If `None` is returned, no `xlabel` attribute
+    /// is specified.
+    ///
+    /// Graphviz drops external labels that would overlap, use
+    /// `Option::ForceLabels` to keep them all.
+    fn node_xlabel(&'a self, _n: &Self::Node) -> Option<Text<'a>> {
+        None
+    }
+
     /// Maps `n` to a style that will be used in the rendered output.
     fn node_style(&'a self, _n: &Self::Node) -> crate::Style {
         crate::Style::None
     }
 
+    /// Maps `n` to a combination of styles, like `filled,dashed`. Defaults
+    /// to the single `node_style`.
+    fn node_styles(&'a self, n: &Self::Node) -> crate::StyleSet {
+        self.node_style(n).into()
+    }
+
+    /// Maps `n` to its color scheme, overriding `graph_colorscheme`. If
+    /// `None` is returned, no `colorscheme` attribute is specified.
+    fn node_colorscheme(&'a self, _node: &Self::Node) -> Option<Text<'a>> {
+        None
+    }
+
     /// Maps `n` to one of the [graphviz `color` names][1]. If `None`
     /// is returned, no `color` attribute is specified.
     ///
@@ -80,8 +162,81 @@ pub trait Labeller<'a> {
         None
     }
 
+    /// Maps `n` to the color filling it with `Style::Filled`, like
+    /// `node_color`. If `None` is returned, no `fillcolor` attribute is
+    /// specified, unless `Option::FillFromColor` is given.
+    fn node_fillcolor(&'a self, _node: &Self::Node) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `n` to the `URL` it links to in SVG and image map outputs.
+    /// If `None` is returned, no `URL` attribute is specified.
+    fn node_url(&'a self, _node: &Self::Node) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `n` to the browser window `target` of its `URL`, like
+    /// `_blank` to open it in a new tab. If `None` is returned, no
+    /// `target` attribute is specified.
+    fn node_target(&'a self, _node: &Self::Node) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `n` to a `comment`, kept in the SVG or PostScript output to
+    /// carry metadata. If `None` is returned, no `comment` attribute is
+    /// specified.
+    fn node_comment(&'a self, _node: &Self::Node) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `n` to its `skew`, shearing a `polygon` shape: positive
+    /// values skew its top to the right. If `None` is returned, no
+    /// `skew` attribute is specified.
+    fn node_skew(&'a self, _node: &Self::Node) -> Option<f64> {
+        None
+    }
+
+    /// Maps `n` to its `distortion`, widening the top of a `polygon`
+    /// shape for positive values, its bottom for negative ones. If `None`
+    /// is returned, no `distortion` attribute is specified.
+    fn node_distortion(&'a self, _node: &Self::Node) -> Option<f64> {
+        None
+    }
+
+    /// Maps `n` to the width of its border, in points. If `None` is
+    /// returned, or a value that isn't finite, no `penwidth` attribute is
+    /// specified.
+    fn node_penwidth(&'a self, _node: &Self::Node) -> Option<f64> {
+        None
+    }
+
+    /// Maps `n` to the font size of its label, in points. If `None` is
+    /// returned, no `fontsize` attribute is specified.
+    fn node_fontsize(&'a self, _node: &Self::Node) -> Option<f64> {
+        None
+    }
+
+    /// Maps `n` to the color of its label. If `None` is returned, no
+    /// `fontcolor` attribute is specified.
+    fn node_fontcolor(&'a self, _node: &Self::Node) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `n` to `true` to freeze it at its `pos` with neato and fdp.
+    /// Defaults to `false`, where no `pin` attribute is specified.
+    fn node_pin(&'a self, _node: &Self::Node) -> bool {
+        false
+    }
+
+    /// Additional attributes of `n`, written after the other ones. Useful
+    /// to pass through attributes not supported otherwise, like `pos`.
+    fn node_attributes(&'a self, _node: &Self::Node) -> Vec<(String, Text<'a>)> {
+        Vec::new()
+    }
+
     /// Maps `e` to arrow style that will be used on the end of an edge.
-    /// Defaults to default arrow style.
+    /// Defaults to default arrow style. Ignored, like the other arrow
+    /// attributes, when the graph is undirected.
     fn edge_end_arrow(&'a self, _e: &Self::Edge) -> crate::Arrow {
         crate::Arrow::default()
     }
@@ -92,6 +247,19 @@ pub trait Labeller<'a> {
         crate::Arrow::default()
     }
 
+    /// Maps `e` to the ends its arrows are drawn at. If `None` is
+    /// returned, `dir=both` is only specified for a non default
+    /// `edge_start_arrow`.
+    fn edge_dir(&'a self, _e: &Self::Edge) -> Option<crate::Direction> {
+        None
+    }
+
+    /// Maps `e` to a scale factor of its arrowheads. If `None` is
+    /// returned, no `arrowsize` attribute is specified.
+    fn edge_arrowsize(&'a self, _e: &Self::Edge) -> Option<f64> {
+        None
+    }
+
     /// Maps `e` to a label that will be used in the rendered output.
     /// The label need not be unique, and may be the empty string; the
     /// default is in fact the empty string.
@@ -99,11 +267,72 @@ pub trait Labeller<'a> {
         Text::LabelStr("".into())
     }
 
+    /// Maps `e` to an external label, placed near the edge without
+    /// affecting the layout. If `None` is returned, no `xlabel` attribute
+    /// is specified.
+    ///
+    /// Graphviz drops external labels that would overlap, use
+    /// `Option::ForceLabels` to keep them all.
+    fn edge_xlabel(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `e` to the label placed near its head. If `None` is returned,
+    /// no `headlabel` attribute is specified.
+    fn edge_headlabel(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `e` to the label placed near its tail. If `None` is returned,
+    /// no `taillabel` attribute is specified.
+    fn edge_taillabel(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `e` to the color of its head label only, unlike
+    /// `edge_labelfontcolor`. Graphviz having no such attribute, the
+    /// label is written as an HTML label in a `<font>` of this color: the
+    /// line breaks of an `EscStr` become `<br/>`, but `\N` and the like
+    /// are no longer substituted.
+    fn edge_headlabel_color(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `e` to the color of its tail label only, like
+    /// `edge_headlabel_color`.
+    fn edge_taillabel_color(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `e` to the font of its head and tail labels. If `None` is
+    /// returned, no `labelfontname` attribute is specified.
+    fn edge_labelfontname(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `e` to the font size, in points, of its head and tail labels.
+    /// If `None` is returned, no `labelfontsize` attribute is specified.
+    fn edge_labelfontsize(&'a self, _e: &Self::Edge) -> Option<f64> {
+        None
+    }
+
+    /// Maps `e` to the font color of its head and tail labels. If `None`
+    /// is returned, no `labelfontcolor` attribute is specified.
+    fn edge_labelfontcolor(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
     /// Maps `e` to a style that will be used in the rendered output.
     fn edge_style(&'a self, _e: &Self::Edge) -> crate::Style {
         crate::Style::None
     }
 
+    /// Maps `e` to a combination of styles, like `bold,dotted`. Defaults
+    /// to the single `edge_style`.
+    fn edge_styles(&'a self, e: &Self::Edge) -> crate::StyleSet {
+        self.edge_style(e).into()
+    }
+
     /// Maps `e` to one of the [graphviz `color` names][1]. If `None`
     /// is returned, no `color` attribute is specified.
     ///
@@ -112,6 +341,115 @@ pub trait Labeller<'a> {
         None
     }
 
+    /// Maps `e` to the `URL` it links to in SVG and image map outputs.
+    /// If `None` is returned, no `URL` attribute is specified.
+    fn edge_url(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `e` to the browser window `target` of its `URL`. If `None`
+    /// is returned, no `target` attribute is specified.
+    fn edge_target(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `e` to a `comment`, like `node_comment`.
+    fn edge_comment(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `e` to the `edgetooltip` shown when hovering the edge itself
+    /// in SVG outputs. If `None` is returned, no `edgetooltip` attribute
+    /// is specified.
+    fn edge_tooltip(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `e` to the `labeltooltip` shown when hovering its `label`,
+    /// like `edge_tooltip`.
+    fn edge_labeltooltip(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `e` to the `headtooltip` shown when hovering its head, like
+    /// `edge_tooltip`.
+    fn edge_headtooltip(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `e` to the `tailtooltip` shown when hovering its tail, like
+    /// `edge_tooltip`.
+    fn edge_tailtooltip(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Additional attributes of `e`, written after the other ones.
+    fn edge_attributes(&'a self, _e: &Self::Edge) -> Vec<(String, Text<'a>)> {
+        Vec::new()
+    }
+
+    /// Maps `e` to its `weight`, a layout hint that keeps heavier edges
+    /// shorter and straighter. If `None` is returned, no `weight`
+    /// attribute is specified.
+    fn edge_weight(&'a self, _e: &Self::Edge) -> Option<f64> {
+        None
+    }
+
+    /// Maps `e` to an integer `weight`, written without a decimal point,
+    /// which some engines handle differently. It takes precedence over
+    /// `edge_weight`. If `None` is returned, `edge_weight` is used.
+    fn edge_weight_int(&'a self, _e: &Self::Edge) -> Option<i64> {
+        None
+    }
+
+    /// Maps `e` to whether it constrains the ranks of its endpoints. If
+    /// `false` is returned, the `constraint=false` attribute is specified.
+    fn edge_constraint(&'a self, _e: &Self::Edge) -> bool {
+        true
+    }
+
+    /// Maps `e` to its preferred length, in inches, used by neato and
+    /// fdp as the rest length of the spring. If `None` is returned, no
+    /// `len` attribute is specified.
+    fn edge_len(&'a self, _e: &Self::Edge) -> Option<f64> {
+        None
+    }
+
+    /// Maps `e` to the font size of its label, like `node_fontsize`.
+    fn edge_fontsize(&'a self, _e: &Self::Edge) -> Option<f64> {
+        None
+    }
+
+    /// Maps `e` to the color of its label, like `node_fontcolor`.
+    fn edge_fontcolor(&'a self, _e: &Self::Edge) -> Option<Text<'a>> {
+        None
+    }
+
+    /// Maps `e` to the width of its line, in points, like
+    /// `node_penwidth`.
+    fn edge_penwidth(&'a self, _e: &Self::Edge) -> Option<f64> {
+        None
+    }
+
+    /// Maps `e` to the port, and compass point, of its source node the
+    /// edge starts from. If `None` is returned, the edge starts from the
+    /// node itself. Ignored when the source is a subgraph.
+    fn edge_source_port(
+        &'a self,
+        _e: &Self::Edge,
+    ) -> Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)> {
+        None
+    }
+
+    /// Maps `e` to the port, and compass point, of its target node the
+    /// edge ends to, like `edge_source_port`.
+    fn edge_target_port(
+        &'a self,
+        _e: &Self::Edge,
+    ) -> Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)> {
+        None
+    }
+
     /// Maps `s` to a unique subgraph identifier.
     /// Prefix this identifier by `cluster_` to draw this subgraph in its own distinct retangle.
     fn subgraph_id(&'a self, _s: &Self::Subgraph) -> Option<crate::Id<'a>> {
@@ -140,14 +478,28 @@ pub trait Labeller<'a> {
         None
     }
 
+    /// Maps `s` to the font size of its label, in points. If `None` is
+    /// returned, no `fontsize` attribute is specified.
+    fn subgraph_fontsize(&'a self, _s: &Self::Subgraph) -> Option<f64> {
+        None
+    }
+
     /// The kind of graph, defaults to `Kind::Digraph`.
     #[inline]
     fn kind(&self) -> crate::Kind {
         crate::Kind::Digraph
     }
+
+    /// Returns `true` to declare the graph `strict`, graphviz then merging
+    /// the edges joining the same nodes. Defaults to `false`.
+    #[inline]
+    fn strict(&self) -> bool {
+        false
+    }
 }
 
 /// The text for a graphviz label on a node or edge.
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Text<'a> {
     /// This kind of label preserves the text directly as is.
     ///
@@ -180,31 +532,45 @@ impl<'a> Text<'a> {
         Self::LabelStr(s.into())
     }
 
+    /// Creates an HTML label from markup, printed as is: entities and
+    /// tags it contains are interpreted by graphviz.
     pub fn html<S: Into<std::borrow::Cow<'a, str>>>(s: S) -> Self {
         Self::HtmlStr(s.into())
     }
 
-    fn escape_char<F>(c: char, mut f: F)
-    where
-        F: FnMut(char),
-    {
-        match c {
-            // not escaping \\, since Graphviz escString needs to
-            // interpret backslashes; see EscStr above.
-            '\\' => f(c),
-            _ => {
-                for c in c.escape_default() {
-                    f(c);
-                }
-            }
-        }
+    /// Creates an HTML label from plain text, escaped with
+    /// [`escape_html`](crate::escape_html) so that it is displayed
+    /// verbatim.
+    #[must_use]
+    pub fn html_escaped(s: &str) -> Self {
+        Self::HtmlStr(crate::escape_html(s).into())
     }
 
-    fn escape_str(s: &str) -> String {
+    /// Escapes `s` for a quoted string, copying runs without special
+    /// characters at once.
+    fn escape_str(s: &str, escape_backslash: bool) -> String {
         let mut out = String::with_capacity(s.len());
-        for c in s.chars() {
-            Self::escape_char(c, |c| out.push(c));
+        let mut last = 0;
+
+        for (i, c) in s.match_indices(['\\', '\n', '"']) {
+            out.push_str(&s[last..i]);
+            last = i + c.len();
+
+            match c {
+                // not escaping \\ for `EscStr`, since Graphviz escString
+                // needs to interpret backslashes; see EscStr above.
+                "\\" if !escape_backslash => out.push('\\'),
+                "\\" => out.push_str(r"\\"),
+                // a raw newline is a centered line break, as `\n` is.
+                "\n" => out.push_str(r"\n"),
+                _ => out.push_str(r#"\""#),
+            }
         }
+        // anything else is valid as is inside a quoted string, whereas
+        // `escape_default` would produce sequences unknown to graphviz,
+        // like `\t` or `\u{e9}`.
+        out.push_str(&s[last..]);
+
         out
     }
 
@@ -217,7 +583,7 @@ impl<'a> Text<'a> {
             Self::EscStr(s) | Self::HtmlStr(s) => s,
             Self::LabelStr(s) => {
                 if s.contains('\\') {
-                    (*s).escape_default().to_string().into()
+                    s.replace('\\', r"\\").into()
                 } else {
                     s
                 }
@@ -225,6 +591,16 @@ impl<'a> Text<'a> {
         }
     }
 
+    /// Converts into a `Text` owning its content, cloning it if borrowed.
+    #[must_use]
+    pub fn into_owned(self) -> Text<'static> {
+        match self {
+            Self::LabelStr(s) => Text::LabelStr(s.into_owned().into()),
+            Self::EscStr(s) => Text::EscStr(s.into_owned().into()),
+            Self::HtmlStr(s) => Text::HtmlStr(s.into_owned().into()),
+        }
+    }
+
     /// Puts `suffix` on a line below this label, with a blank line separator.
     #[must_use]
     pub fn suffix_line(self, suffix: Self) -> Self {
@@ -241,11 +617,268 @@ impl<'a> Text<'a> {
 impl<'a> std::fmt::Display for Text<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match *self {
-            Self::LabelStr(ref s) => format!("\"{}\"", s.escape_default()),
-            Self::EscStr(ref s) => format!("\"{}\"", Self::escape_str(s)),
+            Self::LabelStr(ref s) => {
+                format!("\"{}\"", Self::escape_str(s, true))
+            }
+            Self::EscStr(ref s) => format!("\"{}\"", Self::escape_str(s, false)),
             Self::HtmlStr(ref s) => format!("<{s}>"),
         };
 
         write!(f, "{s}")
     }
 }
+
+/// Builds a graphviz [HTML table][html] label, for a `plaintext` or `none`
+/// shaped node, row by row:
+///
+/// ```
+/// let table = dot2::label::Table::new()
+///     .row()
+///     .cell(dot2::label::Cell::new("name").port("f0").colspan(2))
+///     .row()
+///     .cell(dot2::label::Cell::new("a < b").bgcolor("yellow"))
+///     .cell(dot2::label::Cell::new("c"));
+///
+/// assert_eq!(
+///     dot2::label::Text::from(table).to_string(),
+///     r#"<<table><tr><td port="f0" colspan="2">name</td></tr><tr><td bgcolor="yellow">a &lt; b</td><td>c</td></tr></table>>"#,
+/// );
+/// ```
+///
+/// [html]: https://www.graphviz.org/doc/info/shapes.html#html
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Table {
+    rows: Vec<Vec<Cell>>,
+}
+
+impl Table {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new row, receiving the next cells.
+    #[must_use]
+    pub fn row(mut self) -> Self {
+        self.rows.push(Vec::new());
+        self
+    }
+
+    /// Appends `cell` to the last row, starting the first one if needed.
+    #[must_use]
+    pub fn cell(mut self, cell: Cell) -> Self {
+        match self.rows.last_mut() {
+            Some(row) => row.push(cell),
+            None => self.rows.push(vec![cell]),
+        }
+        self
+    }
+}
+
+impl std::fmt::Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<table>")?;
+
+        for row in &self.rows {
+            write!(f, "<tr>")?;
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            write!(f, "</tr>")?;
+        }
+
+        write!(f, "</table>")
+    }
+}
+
+impl<'a> From<Table> for Text<'a> {
+    fn from(table: Table) -> Self {
+        Self::html(table.to_string())
+    }
+}
+
+/// A cell of a `Table`, its text is escaped with
+/// [`escape_html`](crate::escape_html).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cell {
+    text: String,
+    port: Option<String>,
+    colspan: Option<u32>,
+    rowspan: Option<u32>,
+    bgcolor: Option<String>,
+}
+
+impl Cell {
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Names the cell, so that edges can attach to it with
+    /// `Id::with_port`.
+    #[must_use]
+    pub fn port<S: Into<String>>(mut self, port: S) -> Self {
+        self.port = Some(port.into());
+        self
+    }
+
+    /// Spans the cell over `colspan` columns.
+    #[must_use]
+    pub fn colspan(mut self, colspan: u32) -> Self {
+        self.colspan = Some(colspan);
+        self
+    }
+
+    /// Spans the cell over `rowspan` rows.
+    #[must_use]
+    pub fn rowspan(mut self, rowspan: u32) -> Self {
+        self.rowspan = Some(rowspan);
+        self
+    }
+
+    /// Fills the cell with `bgcolor`, a color name or `#rrggbb` value.
+    #[must_use]
+    pub fn bgcolor<S: Into<String>>(mut self, bgcolor: S) -> Self {
+        self.bgcolor = Some(bgcolor.into());
+        self
+    }
+}
+
+impl std::fmt::Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<td")?;
+
+        if let Some(port) = &self.port {
+            write!(f, r#" port="{}""#, crate::escape_html(port))?;
+        }
+        if let Some(colspan) = self.colspan {
+            write!(f, r#" colspan="{colspan}""#)?;
+        }
+        if let Some(rowspan) = self.rowspan {
+            write!(f, r#" rowspan="{rowspan}""#)?;
+        }
+        if let Some(bgcolor) = &self.bgcolor {
+            write!(f, r#" bgcolor="{}""#, crate::escape_html(bgcolor))?;
+        }
+
+        write!(f, ">{}</td>", crate::escape_html(&self.text))
+    }
+}
+
+/// Builds the label of a `record` or `Mrecord` shaped node, field by
+/// field:
+///
+/// ```
+/// let record = dot2::label::Record::new()
+///     .port_field("f0", "left")
+///     .group(dot2::label::Record::new().field("a|b").port_field("f1", "c d"));
+///
+/// assert_eq!(
+///     dot2::label::Text::from(record).to_string(),
+///     r#""<f0> left|{a\|b|<f1> c\ d}""#,
+/// );
+/// ```
+///
+/// Field texts and ports are escaped, it is an `EscStr` since record
+/// escapes are backslashes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Record {
+    fields: Vec<Field>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Field {
+    Text(Option<String>, String),
+    LeftJustified(Vec<String>),
+    Group(Record),
+}
+
+impl Record {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a field showing `text`.
+    #[must_use]
+    pub fn field<S: Into<String>>(mut self, text: S) -> Self {
+        self.fields.push(Field::Text(None, text.into()));
+        self
+    }
+
+    /// Appends a field showing `text`, named `port` so that edges can
+    /// attach to it with `Labeller::edge_source_port` or
+    /// `Labeller::edge_target_port`.
+    #[must_use]
+    pub fn port_field<P: Into<String>, S: Into<String>>(mut self, port: P, text: S) -> Self {
+        self.fields
+            .push(Field::Text(Some(port.into()), text.into()));
+        self
+    }
+
+    /// Appends a field showing the lines of `text` left-justified, each
+    /// one ended by a `\l` escape. This escape can't be given in `field`
+    /// text, whose backslashes are displayed as is.
+    #[must_use]
+    pub fn left_justified_field<S: AsRef<str>>(mut self, text: S) -> Self {
+        let lines = text.as_ref().lines().map(String::from).collect();
+
+        self.fields.push(Field::LeftJustified(lines));
+        self
+    }
+
+    /// Appends the fields of `record` as a group, laid out in the other
+    /// direction: vertically in a horizontal record and conversely.
+    #[must_use]
+    pub fn group(mut self, record: Self) -> Self {
+        self.fields.push(Field::Group(record));
+        self
+    }
+
+    /// Escapes the characters with a meaning in record labels.
+    fn escape(s: &str) -> String {
+        let mut escaped = String::with_capacity(s.len());
+
+        for c in s.chars() {
+            if matches!(c, '\\' | '|' | '{' | '}' | '<' | '>' | ' ') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+
+        escaped
+    }
+}
+
+impl std::fmt::Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, field) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, "|")?;
+            }
+
+            match field {
+                Field::Text(Some(port), text) => {
+                    write!(f, "<{}> {}", Self::escape(port), Self::escape(text))?;
+                }
+                Field::Text(None, text) => write!(f, "{}", Self::escape(text))?,
+                Field::LeftJustified(lines) => {
+                    for line in lines {
+                        write!(f, r"{}\l", Self::escape(line))?;
+                    }
+                }
+                Field::Group(record) => write!(f, "{{{record}}}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> From<Record> for Text<'a> {
+    fn from(record: Record) -> Self {
+        Self::EscStr(record.to_string().into())
+    }
+}
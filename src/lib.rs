@@ -1,28 +1,70 @@
 #![warn(warnings)]
 #![doc = include_str!("../README.md")]
 
+pub mod arrow;
 pub mod label;
+pub mod render;
 
-mod arrow;
+mod adjacency;
+mod color;
+mod compass;
+mod direction;
+mod edge_endpoint;
 mod errors;
 mod fill;
+mod float;
+mod fontnames;
 mod graph_walk;
 mod id;
+mod indent_writer;
+mod induced_subgraph;
 mod kind;
-mod render;
+#[cfg(feature = "mermaid")]
+mod mermaid;
+mod neato_mode;
+mod overlap;
+mod packmode;
+mod progress;
+mod rank;
+mod rank_sep;
+mod rankdir;
 mod side;
+mod split;
 mod style;
+mod visitor;
+mod warning;
 
 pub use arrow::Arrow;
+pub use color::Color;
+pub use compass::Compass;
+pub use direction::Direction;
+pub use edge_endpoint::EdgeEndpoint;
 pub use errors::*;
 pub use fill::Fill;
+pub use float::Float;
+pub use fontnames::Fontnames;
 pub use graph_walk::GraphWalk;
-pub use id::Id;
+pub use id::{Id, IdMode};
+pub use induced_subgraph::InducedSubgraph;
 pub use kind::Kind;
 pub use label::Labeller;
-pub use render::{render, render_opts};
+#[cfg(feature = "mermaid")]
+pub use mermaid::render_mermaid;
+pub use neato_mode::NeatoMode;
+pub use overlap::Overlap;
+pub use packmode::Packmode;
+pub use progress::Progress;
+pub use rank::Rank;
+pub use rank_sep::RankSep;
+pub use rankdir::Rankdir;
+pub use render::{
+    render, render_all, render_opts, render_owned, render_split, render_with_progress,
+    render_with_warnings, validate, write_attr, write_attr_opt,
+};
 pub use side::Side;
-pub use style::Style;
+pub use style::{Style, StyleSet};
+pub use visitor::{walk, Visitor};
+pub use warning::Warning;
 
 /// Escape tags in such a way that it is suitable for inclusion in a
 /// Graphviz HTML label.
@@ -34,6 +76,30 @@ pub fn escape_html(s: &str) -> String {
         .replace('>', "&gt;")
 }
 
+/// Like `escape_html`, for text in an attribute of a Graphviz HTML label,
+/// like `<td title="...">`: it also escapes `'`, and tabs and line breaks
+/// as numeric entities, whereas other ASCII control characters, rejected
+/// by graphviz, are stripped.
+#[must_use]
+pub fn escape_html_attr(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '\t' | '\n' | '\r' => escaped.push_str(&format!("&#{};", c as u32)),
+            c if c.is_ascii_control() => (),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
 pub type Nodes<'a, N> = std::borrow::Cow<'a, [N]>;
 pub type Edges<'a, E> = std::borrow::Cow<'a, [E]>;
 pub type Subgraphs<'a, S> = std::borrow::Cow<'a, [S]>;
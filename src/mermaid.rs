@@ -0,0 +1,73 @@
+/// Renders graph `g` into the writer `w` as a mermaid.js flowchart, laid
+/// out from top to bottom. Only nodes, edges and their labels are
+/// rendered: DOT attributes have no mermaid equivalent. Nodes are given
+/// ids of their own, `n0`, `n1` and so on.
+pub fn render_mermaid<'a, N, E, S, G, W>(g: &'a G, w: &mut W) -> crate::Result
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+    W: std::io::Write,
+{
+    writeln!(w, "flowchart TD")?;
+
+    let mut ids = std::collections::HashMap::new();
+
+    for n in g.nodes().iter() {
+        mermaid_id(g, w, &mut ids, n)?;
+    }
+
+    let edgeop = if g.kind().is_directed() { "-->" } else { "---" };
+
+    for e in crate::graph_walk::all_edges(g) {
+        let source = mermaid_id(g, w, &mut ids, &g.source(&e))?;
+        let target = mermaid_id(g, w, &mut ids, &g.target(&e))?;
+        let label = text(&g.edge_label(&e));
+
+        if label.is_empty() {
+            writeln!(w, "    {source} {edgeop} {target}")?;
+        } else {
+            writeln!(w, r#"    {source} {edgeop}|"{label}"| {target}"#)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the mermaid id of `n`, declaring the node the first time it is
+/// met. DOT ids may be quoted strings, or mermaid keywords like `end`, so
+/// nodes are numbered in their order of appearance instead.
+fn mermaid_id<'a, N, E, S, G, W>(
+    g: &'a G,
+    w: &mut W,
+    ids: &mut std::collections::HashMap<String, String>,
+    n: &N,
+) -> crate::Result<String>
+where
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>,
+    W: std::io::Write,
+{
+    let id = g.node_id(n)?.as_str().to_string();
+
+    if let Some(mermaid_id) = ids.get(&id) {
+        return Ok(mermaid_id.clone());
+    }
+
+    let mermaid_id = format!("n{}", ids.len());
+    writeln!(w, r#"    {mermaid_id}["{}"]"#, text(&g.node_label(n)?))?;
+    ids.insert(id, mermaid_id.clone());
+
+    Ok(mermaid_id)
+}
+
+/// The content of `label` for a quoted mermaid string, where quotes are
+/// written as an entity.
+fn text(label: &crate::label::Text<'_>) -> String {
+    let (crate::label::Text::LabelStr(s)
+    | crate::label::Text::EscStr(s)
+    | crate::label::Text::HtmlStr(s)) = label;
+
+    s.replace('"', "#quot;")
+}
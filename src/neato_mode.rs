@@ -0,0 +1,29 @@
+/// The algorithm neato uses to minimize its energy model, see
+/// <https://www.graphviz.org/docs/attrs/mode/>.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum NeatoMode {
+    /// Stress majorization, the default.
+    Major,
+    /// Kamada-Kawai gradient descent.
+    KK,
+    /// Stochastic gradient descent.
+    Sgd,
+    /// Hierarchical stress majorization.
+    Hier,
+    /// Stress majorization with separation constraints.
+    Ipsep,
+}
+
+impl std::fmt::Display for NeatoMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Major => "major",
+            Self::KK => "KK",
+            Self::Sgd => "sgd",
+            Self::Hier => "hier",
+            Self::Ipsep => "ipsep",
+        };
+
+        write!(f, "{s}")
+    }
+}
@@ -0,0 +1,29 @@
+/// How neato and sfdp remove node overlaps, see
+/// <https://www.graphviz.org/docs/attrs/overlap/>.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Overlap {
+    /// Prism proximity graph algorithm, optionally with this many
+    /// attempts, `prism0` only scaling the layout.
+    Prism(Option<u32>),
+    /// Scales the layout uniformly.
+    Scale,
+    /// Compresses the layout while keeping it free of overlaps.
+    Compress,
+    /// Quadratic optimization preserving the layout geometry.
+    Vpsc,
+    /// Voronoi diagram based removal.
+    Voronoi,
+}
+
+impl std::fmt::Display for Overlap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Prism(Some(attempts)) => write!(f, "prism{attempts}"),
+            Self::Prism(None) => write!(f, "prism"),
+            Self::Scale => write!(f, "scale"),
+            Self::Compress => write!(f, "compress"),
+            Self::Vpsc => write!(f, "vpsc"),
+            Self::Voronoi => write!(f, "voronoi"),
+        }
+    }
+}
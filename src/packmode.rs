@@ -0,0 +1,26 @@
+/// How disconnected components are packed together, see
+/// <https://www.graphviz.org/docs/attr-types/packMode/>.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Packmode {
+    /// Packs components at the node level.
+    Node,
+    /// Packs components at the cluster level.
+    Clust,
+    /// Packs components using their bounding boxes.
+    Graph,
+    /// Packs components in a grid, row by row.
+    Array,
+}
+
+impl std::fmt::Display for Packmode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Node => "node",
+            Self::Clust => "clust",
+            Self::Graph => "graph",
+            Self::Array => "array",
+        };
+
+        write!(f, "{s}")
+    }
+}
@@ -0,0 +1,9 @@
+/// How much of a graph is rendered, reported by
+/// [`render_with_progress`](crate::render::render_with_progress).
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct Progress {
+    /// The number of nodes emitted so far.
+    pub nodes: usize,
+    /// The number of edges emitted so far.
+    pub edges: usize,
+}
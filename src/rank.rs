@@ -0,0 +1,29 @@
+/// The rank constraint on the nodes of a subgraph, see
+/// <https://www.graphviz.org/docs/attrs/rank/>.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Rank {
+    /// All the nodes are on the same rank.
+    Same,
+    /// All the nodes are on the minimum rank.
+    Min,
+    /// All the nodes are on the maximum rank.
+    Max,
+    /// All the nodes are on the minimum rank, and only them.
+    Source,
+    /// All the nodes are on the maximum rank, and only them.
+    Sink,
+}
+
+impl std::fmt::Display for Rank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Same => "same",
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::Source => "source",
+            Self::Sink => "sink",
+        };
+
+        write!(f, "{s}")
+    }
+}
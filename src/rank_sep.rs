@@ -0,0 +1,21 @@
+/// The separation between ranks, see
+/// <https://www.graphviz.org/docs/attrs/ranksep/>.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RankSep {
+    /// The minimum separation, in inches.
+    Inches(crate::Float),
+    /// Ranks are equally spaced, by the default separation.
+    Equally,
+    /// Ranks are equally spaced, by at least this separation in inches.
+    InchesEqually(crate::Float),
+}
+
+impl std::fmt::Display for RankSep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Inches(inches) => write!(f, "{inches}"),
+            Self::Equally => write!(f, "equally"),
+            Self::InchesEqually(inches) => write!(f, "{inches} equally"),
+        }
+    }
+}
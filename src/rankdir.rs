@@ -0,0 +1,26 @@
+/// The direction ranks are laid out in, see
+/// <https://www.graphviz.org/docs/attr-types/rankdir/>.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Rankdir {
+    /// From top to bottom, the default.
+    TB,
+    /// From left to right.
+    LR,
+    /// From bottom to top.
+    BT,
+    /// From right to left.
+    RL,
+}
+
+impl std::fmt::Display for Rankdir {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::TB => "TB",
+            Self::LR => "LR",
+            Self::BT => "BT",
+            Self::RL => "RL",
+        };
+
+        write!(f, "{s}")
+    }
+}
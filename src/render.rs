@@ -7,9 +7,116 @@ pub enum Option {
     NoNodeStyles,
     NoNodeColors,
     NoArrows,
+    /// Don't default the shape of nodes with an HTML label to `none`.
+    NoHtmlShape,
+    /// Writes node and edge attributes one per line, easier to diff
+    /// when there are many of them.
+    WrapAttributes,
+    /// Checks the graph content, returning an error instead of emitting
+    /// something graphviz would reject or ignore.
+    Validate,
+    /// Emits edges by descending `Labeller::edge_weight_int` or
+    /// `Labeller::edge_weight`, edges without
+    /// weight counting as graphviz default weight of 1.
+    SortEdgesByWeight,
+    /// Emits the attributes of each element sorted by key, for output
+    /// that diffs well whatever order attributes are given in.
+    SortAttributes,
 
     Fontname(String),
+    /// Controls how font names are written in SVG output.
+    Fontnames(crate::Fontnames),
     DarkTheme,
+    /// With `DarkTheme`, lightens node, edge and subgraph colors too dark
+    /// to be seen on the black background. Only colors `Color::parse`
+    /// understands are changed.
+    LightenColors,
+
+    /// Enables sfdp quadtree approximation of long range forces,
+    /// needed to lay out very large graphs in a reasonable time.
+    Quadtree(bool),
+    /// How neato and sfdp remove node overlaps.
+    Overlap(crate::Overlap),
+    /// Scales the drawing produced by sfdp overlap removal.
+    OverlapScaling(crate::Float),
+    /// Places every `xlabel`, even when they overlap other labels.
+    ForceLabels,
+    /// Rotates the drawing by this many degrees, `90` for landscape.
+    Rotate(u16),
+    /// Lays out each disconnected component separately, then packs them
+    /// together.
+    Pack(bool),
+    /// How `Pack` arranges the components.
+    Packmode(crate::Packmode),
+    /// Bounds the dot network simplex iterations used for ranking, as a
+    /// factor of the number of nodes.
+    Nslimit(crate::Float),
+    /// Scales the dot mincross iterations used for ordering nodes.
+    Mclimit(crate::Float),
+    /// The neato energy minimization algorithm.
+    NeatoMode(crate::NeatoMode),
+    /// Margin kept around nodes when removing overlaps.
+    Sep(crate::Float),
+    /// Margin kept around nodes when routing edges as splines, should be
+    /// strictly less than `Sep`.
+    Esep(crate::Float),
+    /// Writes the `Labeller::node_color` of nodes with `Style::Filled`
+    /// and no `Labeller::node_fillcolor` as their `fillcolor` too, for
+    /// tools that don't fall back to `color` as graphviz does.
+    FillFromColor,
+    /// The background color of the drawing. Between it and the black
+    /// background of `DarkTheme`, the last option given is used, but
+    /// `DarkTheme` keeps its white foreground either way.
+    Bgcolor(crate::label::Text<'static>),
+    /// The maximum width and height of the drawing, in inches.
+    Size(crate::Float, crate::Float),
+    /// How the drawing is scaled to `Size`, like `fill`, `compress` or
+    /// an aspect ratio.
+    Ratio(String),
+    /// The resolution of bitmap outputs, in dots per inch.
+    Dpi(u32),
+    /// The direction ranks are laid out in, in dot.
+    Rankdir(crate::Rankdir),
+    /// The separation between ranks, in dot.
+    RankSep(crate::RankSep),
+    /// Margin, in inches, around the whole drawing so that it isn't
+    /// clipped at its edges.
+    Pad(crate::Float),
+    /// Links this external CSS stylesheet in SVG output.
+    Stylesheet(String),
+    /// The length, in bytes, over which `Option::Validate` reports an id
+    /// with `Warning::LongId`.
+    MaxIdLength(usize),
+}
+
+impl Option {
+    /// Reads options from the environment, for command line tools:
+    ///
+    /// * `DOT2_THEME=dark` gives `Option::DarkTheme`;
+    /// * `DOT2_FONT=<name>` gives `Option::Fontname`.
+    ///
+    /// Unset variables, empty and unknown values are ignored.
+    #[must_use]
+    pub fn from_env() -> Vec<Self> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// Reads options like `from_env`, getting the value of variables
+    /// from `var`.
+    #[must_use]
+    pub fn from_vars(var: impl Fn(&str) -> std::option::Option<String>) -> Vec<Self> {
+        let mut options = Vec::new();
+
+        if var("DOT2_THEME").is_some_and(|theme| theme == "dark") {
+            options.push(Self::DarkTheme);
+        }
+
+        if let Some(font) = var("DOT2_FONT").filter(|font| !font.is_empty()) {
+            options.push(Self::Fontname(font));
+        }
+
+        options
+    }
 }
 
 /// Renders directed graph `g` into the writer `w` in DOT syntax.
@@ -28,6 +135,10 @@ where
 
 /// Renders directed graph `g` into the writer `w` in DOT syntax.
 /// (Main entry point for the library.)
+///
+/// Rendering stops at the first write error, returned as `Error::Io`:
+/// `w` then holds a truncated graph, render into a buffer first if that
+/// matters.
 pub fn render_opts<'a, N, E, S, G, W>(
     g: &'a G,
     w: &mut W,
@@ -41,17 +152,247 @@ where
         + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
     W: std::io::Write,
 {
+    render_with_progress(g, w, options, |_| ())
+}
+
+/// Renders directed graph `g` into the writer `w` in DOT syntax, like
+/// `render_opts`, calling `on_progress` after each node and edge, to
+/// report the progress of long renders.
+pub fn render_with_progress<'a, N, E, S, G, W, P>(
+    g: &'a G,
+    w: &mut W,
+    options: &[self::Option],
+    mut on_progress: P,
+) -> crate::Result
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+    W: std::io::Write,
+    P: FnMut(crate::Progress),
+{
+    let mut tracker = Tracker {
+        progress: crate::Progress::default(),
+        on_progress: Some(&mut on_progress),
+    };
+
+    if g.strict() {
+        write!(w, "strict ")?;
+    }
     writeln!(w, "{} {} {{", g.kind(), g.graph_id()?)?;
 
-    render_subgraphs(g, &g.subgraphs(), w, options)?;
-    render_nodes(g, &g.nodes(), w, options)?;
-    render_edges(g, &g.edges(), w, options)?;
+    render_graph_label(g, w, options)?;
+
+    render_subgraphs(g, &g.subgraphs(), w, options, &mut tracker)?;
+    write_nodes(g, &g.nodes(), w, options, &mut tracker)?;
+    render_ranks(g, &g.nodes(), w)?;
+    render_edges_iter(g, g.edges_iter(), w, options, &mut tracker)?;
 
     writeln!(w, "}}")?;
 
     Ok(())
 }
 
+/// Renders directed graph `g` into the writer `w` in DOT syntax, like
+/// `render_opts`, and gives `w` back.
+pub fn render_owned<'a, N, E, S, G, W>(
+    g: &'a G,
+    mut w: W,
+    options: &[self::Option],
+) -> crate::Result<W>
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+    W: std::io::Write,
+{
+    render_opts(g, &mut w, options)?;
+
+    Ok(w)
+}
+
+/// Renders the graph labelled by `labeller` and walked by `walk` into the
+/// writer `w` in DOT syntax, like `render_opts`, for when the two traits
+/// are implemented by separate types.
+pub fn render_split<'a, N, E, S, L, K, W>(
+    labeller: &'a L,
+    walk: &'a K,
+    w: &mut W,
+    options: &[self::Option],
+) -> crate::Result
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    L: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>,
+    K: crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+    W: std::io::Write,
+{
+    render_opts(&crate::split::Split { labeller, walk }, w, options)
+}
+
+/// Renders every graph of `graphs` into the writer `w`, separated by a
+/// blank line. Ids only need to be unique within their graph.
+pub fn render_all<'a, N, E, S, G, W>(
+    graphs: &[&'a G],
+    w: &mut W,
+    options: &[self::Option],
+) -> crate::Result
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+    W: std::io::Write,
+{
+    for (i, g) in graphs.iter().enumerate() {
+        if i > 0 {
+            writeln!(w)?;
+        }
+
+        render_opts(*g, w, options)?;
+    }
+
+    Ok(())
+}
+
+/// Checks that `g` renders with `options`, as `Option::Validate` does,
+/// without producing any output.
+pub fn validate<'a, N, E, S, G>(g: &'a G, options: &[self::Option]) -> crate::Result
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+{
+    let mut options = options.to_vec();
+    options.push(self::Option::Validate);
+
+    render_opts(g, &mut std::io::sink(), &options)
+}
+
+/// Renders directed graph `g` into the writer `w` in DOT syntax, like
+/// `render_opts`, and returns the non-fatal issues found in the graph.
+pub fn render_with_warnings<'a, N, E, S, G, W>(
+    g: &'a G,
+    w: &mut W,
+    options: &[self::Option],
+) -> crate::Result<Vec<crate::Warning>>
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+    W: std::io::Write,
+{
+    render_opts(g, w, options)?;
+
+    warnings(g, options)
+}
+
+fn warnings<'a, N, E, S, G>(
+    g: &'a G,
+    options: &[self::Option],
+) -> crate::Result<Vec<crate::Warning>>
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+{
+    let mut warnings = Vec::new();
+    let mut ids = std::collections::HashSet::new();
+
+    let validate = options.contains(&self::Option::Validate);
+    let max_id_length = options
+        .iter()
+        .find_map(|option| match option {
+            self::Option::MaxIdLength(length) => Some(*length),
+            _ => None,
+        })
+        .unwrap_or(16_384);
+    let graph_id = g.graph_id()?;
+    if validate && graph_id.as_str().len() > max_id_length {
+        warnings.push(crate::Warning::LongId(graph_id.as_str().to_string()));
+    }
+
+    for n in g.nodes().iter() {
+        let node_id = g.node_id(n)?;
+        if validate && node_id.as_str().len() > max_id_length {
+            warnings.push(crate::Warning::LongId(node_id.as_str().to_string()));
+        }
+
+        let id = node_id.to_string();
+        if !ids.insert(id.clone()) {
+            warnings.push(crate::Warning::DuplicateId(id));
+        }
+    }
+
+    for e in crate::graph_walk::all_edges(g) {
+        for style in g.edge_styles(&e).styles {
+            if !style.is_edge_style() {
+                warnings.push(crate::Warning::NodeStyleOnEdge(style));
+            }
+        }
+
+        // dot ignores the weight of an edge for ranking once it stops
+        // constraining its endpoints.
+        let weight = edge_weight(g, &e).unwrap_or(1.);
+        if validate && !g.edge_constraint(&e) && weight > 1. {
+            let source = g.node_id(&g.source(&e))?;
+            let target = g.node_id(&g.target(&e))?;
+
+            warnings.push(crate::Warning::WeightWithoutConstraint(format!(
+                "{source} {} {target}",
+                g.kind().edgeop()
+            )));
+        }
+    }
+
+    Ok(warnings)
+}
+
+/// Renders the graph label statements, skipped like subgraph labels with
+/// `Option::NoNodeLabels`.
+fn render_graph_label<'a, N, E, S, G, W>(
+    g: &'a G,
+    w: &mut W,
+    options: &[self::Option],
+) -> crate::Result
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+    W: std::io::Write,
+{
+    if options.contains(&self::Option::NoNodeLabels) {
+        return Ok(());
+    }
+
+    let mut w = crate::indent_writer::IndentWriter::new(w);
+    w.indent();
+
+    if let Some(label) = g.graph_label() {
+        writeln!(w, "label={label};")?;
+    }
+
+    if let Some(labelloc) = g.graph_labelloc() {
+        writeln!(w, r#"labelloc="{labelloc}";"#)?;
+    }
+
+    Ok(())
+}
+
 fn render_subgraphs<
     'a,
     N: Clone + 'a,
@@ -65,42 +406,191 @@ fn render_subgraphs<
     subgraphs: &crate::Subgraphs<'a, S>,
     w: &mut W,
     options: &[crate::render::Option],
+    tracker: &mut Tracker,
 ) -> crate::Result {
-    for s in subgraphs.iter() {
-        let id = g
-            .subgraph_id(s)
-            .map(|x| format!("{} ", x.name))
-            .unwrap_or_default();
+    let mut w = crate::indent_writer::IndentWriter::new(w);
+    w.indent();
 
-        writeln!(w, "    subgraph {id}{{")?;
+    write_subgraphs(g, subgraphs, &mut w, options, tracker)
+}
 
-        if !options.contains(&crate::render::Option::NoNodeLabels) {
-            writeln!(w, "        label={};", g.subgraph_label(s))?;
+/// Writes `subgraphs` at the current indentation, nesting their
+/// `GraphWalk::subgraph_children`.
+fn write_subgraphs<'a, N, E, S, G, W>(
+    g: &'a G,
+    subgraphs: &crate::Subgraphs<'a, S>,
+    w: &mut crate::indent_writer::IndentWriter<W>,
+    options: &[crate::render::Option],
+    tracker: &mut Tracker,
+) -> crate::Result
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+    W: std::io::Write,
+{
+    for (i, s) in subgraphs.iter().enumerate() {
+        if i > 0 {
+            writeln!(w)?;
+        }
+
+        let id = g.subgraph_id(s);
+        let rank = g.subgraph_rank(s);
+        // A rank group without id needs no `subgraph` keyword nor label.
+        let group = id.is_none() && rank.is_some();
+
+        if group {
+            writeln!(w, "{{")?;
+        } else {
+            let id = id.map(|x| format!("{x} ")).unwrap_or_default();
+            writeln!(w, "subgraph {id}{{")?;
+        }
+        w.indent();
+
+        if !group && !options.contains(&crate::render::Option::NoNodeLabels) {
+            writeln!(w, "label={};", g.subgraph_label(s))?;
+        }
+
+        if let Some(rank) = rank {
+            writeln!(w, "rank={rank};")?;
         }
 
         let style = g.subgraph_style(s);
         if !options.contains(&crate::render::Option::NoNodeStyles) && style != crate::Style::None {
-            writeln!(w, r#"        style="{style}";"#)?;
+            writeln!(w, r#"style="{style}";"#)?;
         }
 
         let color = g.subgraph_color(s);
         if !options.contains(&crate::render::Option::NoNodeColors) {
             if let Some(c) = color {
-                writeln!(w, "        color={c};")?;
+                writeln!(w, "color={};", themed_color(c, options))?;
             }
         }
 
         if let Some(s) = g.subgraph_shape(s) {
-            write!(w, r#"        shape="{s}";"#)?;
+            writeln!(w, r#"shape="{s}";"#)?;
+        }
+
+        if let Some(fontsize) = g.subgraph_fontsize(s).filter(|size| size.is_finite()) {
+            writeln!(w, "fontsize={fontsize};")?;
         }
 
-        writeln!(w)?;
+        let children = g.subgraph_children(s);
+        // Nodes of a nested subgraph are only listed there.
+        let mut nested = std::collections::HashSet::new();
+        for child in children.iter() {
+            nested_node_ids(g, child, &mut nested)?;
+        }
 
         for n in g.subgraph_nodes(s).iter() {
-            writeln!(w, "        {};", g.node_id(n)?)?;
+            let id = g.node_id(n)?.to_string();
+
+            if !nested.contains(&id) {
+                writeln!(w, "{id};")?;
+            }
+        }
+
+        write_subgraphs(g, &children, w, options, tracker)?;
+
+        let mut edges = g.subgraph_edges(s);
+        if options.contains(&self::Option::SortEdgesByWeight) {
+            sort_by_weight(g, edges.to_mut());
+        }
+
+        for e in edges.iter() {
+            write_edge(g, e, w, options)?;
+            tracker.edge();
+        }
+
+        let rank_order = g.rank_order(s);
+        if rank_order.len() > 1 {
+            let ids = rank_order
+                .iter()
+                .map(|n| g.node_id(n).map(|id| id.to_string()))
+                .collect::<crate::Result<Vec<_>>>()?;
+
+            // An anonymous block rather than a subgraph: a `cluster` name
+            // would draw a box around the rank.
+            writeln!(w, "{{")?;
+            w.indent();
+            writeln!(w, "rank=same;")?;
+            writeln!(
+                w,
+                "{}[style=invis];",
+                ids.join(&format!(" {} ", g.kind().edgeop()))
+            )?;
+            w.dedent();
+            writeln!(w, "}}")?;
         }
 
-        writeln!(w, "    }}\n")?;
+        w.dedent();
+        writeln!(w, "}}")?;
+    }
+
+    Ok(())
+}
+
+/// Collects the ids of the nodes of `s` and its nested subgraphs.
+fn nested_node_ids<'a, N, E, S, G>(
+    g: &'a G,
+    s: &S,
+    ids: &mut std::collections::HashSet<String>,
+) -> crate::Result
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+{
+    for n in g.subgraph_nodes(s).iter() {
+        ids.insert(g.node_id(n)?.to_string());
+    }
+
+    for child in g.subgraph_children(s).iter() {
+        nested_node_ids(g, child, ids)?;
+    }
+
+    Ok(())
+}
+
+/// Renders a `rank=same` block for each level of `GraphWalk::node_rank`,
+/// from the lowest.
+fn render_ranks<'a, N, E, S, G, W>(
+    g: &'a G,
+    nodes: &crate::Nodes<'a, N>,
+    w: &mut W,
+) -> crate::Result
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+    W: std::io::Write,
+{
+    let mut w = crate::indent_writer::IndentWriter::new(w);
+    w.indent();
+
+    let mut ranks = std::collections::BTreeMap::<_, Vec<_>>::new();
+
+    for n in nodes.iter() {
+        if let Some(rank) = g.node_rank(n) {
+            ranks.entry(rank).or_default().push(g.node_id(n)?);
+        }
+    }
+
+    for ids in ranks.values() {
+        writeln!(w, "{{")?;
+        w.indent();
+        writeln!(w, "rank=same;")?;
+        for id in ids {
+            writeln!(w, "{id};")?;
+        }
+        w.dedent();
+        writeln!(w, "}}")?;
     }
 
     Ok(())
@@ -120,10 +610,31 @@ where
         + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
     W: std::io::Write,
 {
+    write_nodes(g, nodes, w, options, &mut Tracker::default())
+}
+
+fn write_nodes<'a, N, E, S, G, W>(
+    g: &'a G,
+    nodes: &crate::Nodes<'a, N>,
+    w: &mut W,
+    options: &[crate::render::Option],
+    tracker: &mut Tracker,
+) -> crate::Result
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+    W: std::io::Write,
+{
+    let mut w = crate::indent_writer::IndentWriter::new(w);
+    w.indent();
+
     // Global graph properties
-    let mut graph_attrs = Vec::new();
-    let mut content_attrs = Vec::new();
-    let font;
+    let mut graph_attrs = Attrs::default();
+    let mut node_attrs = Attrs::default();
+    let mut edge_attrs = Attrs::default();
 
     if let Some(fontname) = options.iter().find_map(|option| {
         if let self::Option::Fontname(fontname) = option {
@@ -132,52 +643,237 @@ where
             None
         }
     }) {
-        font = format!(r#"fontname="{fontname}""#);
-        graph_attrs.push(&font[..]);
-        content_attrs.push(&font[..]);
+        let font = format!(r#""{fontname}""#);
+        graph_attrs.push("fontname", &font);
+        node_attrs.push("fontname", &font);
+        edge_attrs.push("fontname", font);
     }
 
+    let bgcolor = options.iter().rev().find_map(|option| match option {
+        self::Option::Bgcolor(bgcolor) => Some(bgcolor.to_string()),
+        self::Option::DarkTheme => Some(r#""black""#.to_string()),
+        _ => None,
+    });
+
+    graph_attrs.push_opt("bgcolor", bgcolor);
+
     if options.contains(&self::Option::DarkTheme) {
-        graph_attrs.push(r#"bgcolor="black""#);
-        graph_attrs.push(r#"fontcolor="white""#);
-        content_attrs.push(r#"color="white""#);
-        content_attrs.push(r#"fontcolor="white""#);
+        graph_attrs.push("fontcolor", r#""white""#);
+        for attrs in [&mut node_attrs, &mut edge_attrs] {
+            attrs.push("color", r#""white""#);
+            attrs.push("fontcolor", r#""white""#);
+        }
+    }
+
+    if options.contains(&self::Option::ForceLabels) {
+        graph_attrs.push("forcelabels", true);
+    }
+
+    for option in options {
+        match option {
+            self::Option::Quadtree(quadtree) => graph_attrs.push("quadtree", quadtree),
+            self::Option::Overlap(overlap) => {
+                graph_attrs.push("overlap", format!(r#""{overlap}""#));
+            }
+            self::Option::OverlapScaling(scaling) => {
+                graph_attrs.push_finite("overlap_scaling", Some(scaling.0));
+            }
+            self::Option::Fontnames(fontnames) => {
+                graph_attrs.push("fontnames", format!(r#""{fontnames}""#));
+            }
+            self::Option::Rotate(rotate) => graph_attrs.push("rotate", rotate),
+            self::Option::Pack(pack) => graph_attrs.push("pack", pack),
+            self::Option::Packmode(packmode) => {
+                graph_attrs.push("packmode", format!(r#""{packmode}""#));
+            }
+            // Factors keep their decimal point, `2.0` rather than `2`.
+            self::Option::Nslimit(crate::Float(nslimit)) if nslimit.is_finite() => {
+                graph_attrs.push("nslimit", format!("{nslimit:?}"));
+            }
+            self::Option::Mclimit(crate::Float(mclimit)) if mclimit.is_finite() => {
+                graph_attrs.push("mclimit", format!("{mclimit:?}"));
+            }
+            self::Option::NeatoMode(mode) => graph_attrs.push("mode", format!(r#""{mode}""#)),
+            self::Option::Sep(sep) => graph_attrs.push_finite("sep", Some(sep.0)),
+            self::Option::Esep(esep) => graph_attrs.push_finite("esep", Some(esep.0)),
+            self::Option::Pad(pad) => graph_attrs.push_finite("pad", Some(pad.0)),
+            self::Option::Rankdir(rankdir) => graph_attrs.push("rankdir", rankdir),
+            self::Option::Size(width, height) if width.0.is_finite() && height.0.is_finite() => {
+                graph_attrs.push("size", format!(r#""{width},{height}""#));
+            }
+            self::Option::Ratio(ratio) => {
+                graph_attrs.push("ratio", crate::label::Text::LabelStr(ratio.as_str().into()));
+            }
+            self::Option::Dpi(dpi) => graph_attrs.push("dpi", dpi),
+            self::Option::RankSep(ranksep) => {
+                let finite = match ranksep {
+                    crate::RankSep::Inches(inches) | crate::RankSep::InchesEqually(inches) => {
+                        inches.0.is_finite()
+                    }
+                    crate::RankSep::Equally => true,
+                };
+
+                if finite {
+                    graph_attrs.push("ranksep", format!(r#""{ranksep}""#));
+                }
+            }
+            self::Option::Stylesheet(stylesheet) => graph_attrs.push(
+                "stylesheet",
+                crate::label::Text::LabelStr(stylesheet.as_str().into()),
+            ),
+            _ => (),
+        }
+    }
+
+    if let Some(colorscheme) = g.graph_colorscheme() {
+        let colorscheme = colorscheme.to_string();
+        graph_attrs.push("colorscheme", &colorscheme);
+        node_attrs.push("colorscheme", &colorscheme);
+        edge_attrs.push("colorscheme", colorscheme);
+    }
+
+    graph_attrs.push_finite("fontsize", g.graph_fontsize());
+
+    graph_attrs.push_opt("comment", g.graph_comment());
+
+    if let Some(id) = g.graph_svg_id() {
+        graph_attrs.push("id", crate::label::Text::LabelStr(id.into()));
     }
 
-    if !(graph_attrs.is_empty() && content_attrs.is_empty()) {
-        writeln!(w, r#"    graph[{}];"#, graph_attrs.join(" "))?;
-        let content_attrs_str = content_attrs.join(" ");
-        writeln!(w, r#"    node[{content_attrs_str}];"#)?;
-        writeln!(w, r#"    edge[{content_attrs_str}];"#)?;
+    for (key, value) in g.graph_attributes() {
+        graph_attrs.push(key, value);
+    }
+
+    for (key, value) in g.node_defaults() {
+        node_attrs.push(key, value);
+    }
+
+    for (key, value) in g.edge_defaults() {
+        edge_attrs.push(key, value);
+    }
+
+    for (statement, mut attrs) in [
+        ("graph", graph_attrs),
+        ("node", node_attrs),
+        ("edge", edge_attrs),
+    ] {
+        if !attrs.0.is_empty() {
+            attrs.sort(options);
+
+            let attrs = attrs
+                .0
+                .iter()
+                .flatten()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect::<Vec<_>>();
+
+            writeln!(w, "{statement}[{}];", attrs.join(" "))?;
+        }
     }
 
     for n in nodes.iter() {
-        write!(w, "    ")?;
         let id = g.node_id(n)?;
 
         write!(w, "{id}")?;
 
+        let mut attrs = Attrs::default();
+
+        let mut html_label = false;
         if !options.contains(&self::Option::NoNodeLabels) {
-            write!(w, "[label={}]", g.node_label(n)?)?;
+            let label = g.node_label(n)?;
+            html_label = matches!(label, crate::label::Text::HtmlStr(_));
+            attrs.push("label", label);
+        }
+
+        if let Some(xlabel) = g.node_xlabel(n) {
+            if !options.contains(&self::Option::NoNodeLabels) {
+                attrs.push("xlabel", xlabel);
+            }
         }
 
-        let style = g.node_style(n);
-        if !options.contains(&self::Option::NoNodeStyles) && style != crate::Style::None {
-            write!(w, r#"[style="{style}"]"#)?;
+        let styles = g.node_styles(n);
+        if !options.contains(&self::Option::NoNodeStyles) && !styles.is_empty() {
+            attrs.push("style", format!(r#""{styles}""#));
         }
 
-        let color = g.node_color(n);
+        attrs.push_opt("colorscheme", g.node_colorscheme(n));
+
         if !options.contains(&self::Option::NoNodeColors) {
-            if let Some(c) = color {
-                write!(w, "[color={c}]")?;
+            let color = g
+                .node_color(n)
+                .map(|c| themed_color(c, options).to_string());
+            let mut fillcolor = g
+                .node_fillcolor(n)
+                .map(|c| themed_color(c, options).to_string());
+
+            if fillcolor.is_none()
+                && options.contains(&self::Option::FillFromColor)
+                && styles.styles.contains(&crate::Style::Filled)
+            {
+                fillcolor.clone_from(&color);
+            }
+
+            attrs.push_opt("color", color);
+            attrs.push_opt("fillcolor", fillcolor);
+        }
+
+        attrs.push_opt("URL", g.node_url(n));
+
+        attrs.push_opt("target", g.node_target(n));
+
+        attrs.push_opt("comment", g.node_comment(n));
+
+        let shape = g.node_shape(n);
+        if let Some(crate::label::Text::LabelStr(s) | crate::label::Text::EscStr(s)) = &shape {
+            if html_label && options.contains(&self::Option::Validate) && !is_html_shape(s) {
+                return Err(crate::Error::IncompatibleHtmlShape(s.to_string()));
             }
         }
 
-        if let Some(s) = g.node_shape(n) {
-            write!(w, "[shape={s}]")?;
+        let skew = g.node_skew(n);
+        let distortion = g.node_distortion(n);
+        if (skew.is_some() || distortion.is_some()) && options.contains(&self::Option::Validate) {
+            let shape = match &shape {
+                Some(crate::label::Text::LabelStr(s) | crate::label::Text::EscStr(s)) => s,
+                Some(crate::label::Text::HtmlStr(_)) => "html",
+                None => "ellipse",
+            };
+
+            if shape != "polygon" {
+                return Err(crate::Error::IncompatiblePolygonShape(shape.to_string()));
+            }
+        }
+
+        match shape {
+            Some(s) => attrs.push("shape", s),
+            // Without it, graphviz draws a box around HTML tables.
+            None if html_label && !options.contains(&self::Option::NoHtmlShape) => {
+                attrs.push("shape", "none");
+            }
+            None => (),
+        }
+
+        attrs.push_finite("skew", skew);
+
+        attrs.push_finite("distortion", distortion);
+
+        attrs.push_finite("penwidth", g.node_penwidth(n));
+
+        attrs.push_finite("fontsize", g.node_fontsize(n));
+
+        attrs.push_opt("fontcolor", g.node_fontcolor(n));
+
+        if g.node_pin(n) {
+            attrs.push("pin", true);
+        }
+
+        for (key, value) in g.node_attributes(n) {
+            attrs.push(key, value);
         }
 
+        write_attrs(&mut w, attrs, options)?;
         writeln!(w, ";")?;
+        tracker.node();
     }
 
     Ok(())
@@ -197,51 +893,491 @@ where
         + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
     W: std::io::Write,
 {
-    for e in edges.iter() {
-        let escaped_label = &g.edge_label(e).to_string();
-        write!(w, "    ")?;
-        let source = g.source(e);
-        let target = g.target(e);
-        let source_id = g.node_id(&source)?;
-        let target_id = g.node_id(&target)?;
+    render_edges_iter(
+        g,
+        edges.iter().cloned(),
+        w,
+        options,
+        &mut Tracker::default(),
+    )
+}
+
+fn render_edges_iter<'a, N, E, S, G, W, I>(
+    g: &'a G,
+    mut edges: I,
+    w: &mut W,
+    options: &[crate::render::Option],
+    tracker: &mut Tracker,
+) -> crate::Result
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+    W: std::io::Write,
+    I: Iterator<Item = E>,
+{
+    let mut w = crate::indent_writer::IndentWriter::new(w);
+    w.indent();
+
+    let mut sorted;
+    let edges: &mut dyn Iterator<Item = E> = if options.contains(&self::Option::SortEdgesByWeight) {
+        let mut buffer = edges.collect::<Vec<_>>();
+        sort_by_weight(g, &mut buffer);
+
+        sorted = buffer.into_iter();
+        &mut sorted
+    } else {
+        &mut edges
+    };
+
+    for e in edges {
+        write_edge(g, &e, &mut w, options)?;
+        tracker.edge();
+    }
+
+    Ok(())
+}
+
+/// Sorts `edges` by descending weight for `Option::SortEdgesByWeight`,
+/// edges without weight counting as graphviz default weight of 1.
+fn sort_by_weight<'a, N, E, S, G>(g: &'a G, edges: &mut [E])
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+{
+    let weight = |e: &E| edge_weight(g, e).unwrap_or(1.);
+    edges.sort_by(|a, b| weight(b).total_cmp(&weight(a)));
+}
 
-        write!(w, "{source_id} {} {target_id}", g.kind().edgeop(),)?;
+/// Writes the statement of edge `e` at the current indentation.
+fn write_edge<'a, N, E, S, G, W>(
+    g: &'a G,
+    e: &E,
+    w: &mut crate::indent_writer::IndentWriter<W>,
+    options: &[crate::render::Option],
+) -> crate::Result
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+    W: std::io::Write,
+{
+    let escaped_label = &g.edge_label(e).to_string();
+    let source_id = endpoint_id(g, g.source_endpoint(e), g.edge_source_port(e))?;
+    let target_id = endpoint_id(g, g.target_endpoint(e), g.edge_target_port(e))?;
 
+    write!(w, "{source_id} {} {target_id}", g.kind().edgeop(),)?;
+
+    let mut attrs = Attrs::default();
+
+    if !options.contains(&self::Option::NoEdgeLabels) {
+        attrs.push("label", escaped_label);
+    }
+
+    if let Some(xlabel) = g.edge_xlabel(e) {
         if !options.contains(&self::Option::NoEdgeLabels) {
-            write!(w, "[label={escaped_label}]")?;
+            attrs.push("xlabel", xlabel);
         }
+    }
 
-        let style = g.edge_style(e);
-        if !options.contains(&self::Option::NoEdgeStyles) && style != crate::Style::None {
-            write!(w, r#"[style="{style}"]"#)?;
+    if !options.contains(&self::Option::NoEdgeLabels) {
+        if let Some(headlabel) = g.edge_headlabel(e) {
+            attrs.push(
+                "headlabel",
+                colored_label(headlabel, g.edge_headlabel_color(e)),
+            );
         }
 
-        let color = g.edge_color(e);
-        if !options.contains(&self::Option::NoEdgeColors) {
-            if let Some(c) = color {
-                write!(w, "[color={c}]")?;
-            }
+        if let Some(taillabel) = g.edge_taillabel(e) {
+            attrs.push(
+                "taillabel",
+                colored_label(taillabel, g.edge_taillabel_color(e)),
+            );
+        }
+    }
+
+    attrs.push_opt("labelfontname", g.edge_labelfontname(e));
+
+    attrs.push_finite("labelfontsize", g.edge_labelfontsize(e));
+
+    attrs.push_opt("labelfontcolor", g.edge_labelfontcolor(e));
+
+    let styles = g.edge_styles(e);
+    if options.contains(&self::Option::Validate) {
+        if let Some(style) = styles.styles.iter().find(|style| !style.is_edge_style()) {
+            return Err(crate::Error::InvalidEdgeStyle(*style));
         }
+    }
+    if !options.contains(&self::Option::NoEdgeStyles) && !styles.is_empty() {
+        attrs.push("style", format!(r#""{styles}""#));
+    }
 
-        let start_arrow = g.edge_start_arrow(e);
-        let end_arrow = g.edge_end_arrow(e);
+    let color = g.edge_color(e);
+    if !options.contains(&self::Option::NoEdgeColors) {
+        if let Some(c) = color {
+            attrs.push("color", themed_color(c, options));
+        }
+    }
 
-        if !options.contains(&self::Option::NoArrows)
-            && (!start_arrow.is_default() || !end_arrow.is_default())
-        {
-            write!(w, "[")?;
-            if !end_arrow.is_default() {
-                write!(w, r#"arrowhead="{end_arrow}""#)?;
+    attrs.push_opt("URL", g.edge_url(e));
+
+    attrs.push_opt("target", g.edge_target(e));
+
+    attrs.push_opt("comment", g.edge_comment(e));
+
+    attrs.push_opt("edgetooltip", g.edge_tooltip(e));
+
+    attrs.push_opt("labeltooltip", g.edge_labeltooltip(e));
+
+    attrs.push_opt("headtooltip", g.edge_headtooltip(e));
+
+    attrs.push_opt("tailtooltip", g.edge_tailtooltip(e));
+
+    match g.edge_weight_int(e) {
+        Some(weight) => attrs.push("weight", weight),
+        None => attrs.push_finite("weight", g.edge_weight(e)),
+    }
+
+    if !g.edge_constraint(e) {
+        attrs.push("constraint", false);
+    }
+
+    attrs.push_finite("len", g.edge_len(e));
+
+    attrs.push_finite("penwidth", g.edge_penwidth(e));
+
+    attrs.push_finite("fontsize", g.edge_fontsize(e));
+
+    attrs.push_opt("fontcolor", g.edge_fontcolor(e));
+
+    let start_arrow = g.edge_start_arrow(e);
+    let end_arrow = g.edge_end_arrow(e);
+    // Undirected edges have no arrows to style.
+    let arrows = g.kind().is_directed() && !options.contains(&self::Option::NoArrows);
+
+    if arrows {
+        let mut group = Vec::new();
+
+        if !end_arrow.is_default() {
+            group.push(("arrowhead".to_string(), format!(r#""{end_arrow}""#)));
+        }
+
+        match g.edge_dir(e) {
+            Some(dir) => group.push(("dir".to_string(), format!(r#""{dir}""#))),
+            // Without it, graphviz doesn't draw the start arrow.
+            None if !start_arrow.is_default() => {
+                group.push(("dir".to_string(), r#""both""#.to_string()))
             }
-            if !start_arrow.is_default() {
-                write!(w, r#" dir="both" arrowtail="{start_arrow}""#)?;
+            None => (),
+        }
+
+        if !start_arrow.is_default() {
+            group.push(("arrowtail".to_string(), format!(r#""{start_arrow}""#)));
+        }
+
+        attrs.push_group(group);
+
+        attrs.push_finite("arrowsize", g.edge_arrowsize(e));
+    }
+
+    for (key, value) in g.edge_attributes(e) {
+        attrs.push(key, value);
+    }
+
+    write_attrs(w, attrs, options)?;
+    writeln!(w, ";")?;
+
+    Ok(())
+}
+
+/// The weight of `e`, from `Labeller::edge_weight_int` or else
+/// `Labeller::edge_weight`.
+fn edge_weight<'a, N, E, S, G>(g: &'a G, e: &E) -> std::option::Option<f64>
+where
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>,
+{
+    g.edge_weight_int(e)
+        .map(|weight| weight as f64)
+        .or_else(|| g.edge_weight(e))
+}
+
+/// Return `true` if `shape` can hold a HTML label.
+fn is_html_shape(shape: &str) -> bool {
+    matches!(
+        shape,
+        "none" | "plaintext" | "plain" | "record" | "Mrecord" | "box" | "rect" | "rectangle"
+    )
+}
+
+/// Lightens `color` if it is too dark for `Option::DarkTheme`, when
+/// `Option::LightenColors` is set.
+fn themed_color<'a>(
+    color: crate::label::Text<'a>,
+    options: &[self::Option],
+) -> crate::label::Text<'a> {
+    if !options.contains(&self::Option::DarkTheme)
+        || !options.contains(&self::Option::LightenColors)
+    {
+        return color;
+    }
+
+    let parsed = match &color {
+        crate::label::Text::LabelStr(s) | crate::label::Text::EscStr(s) => crate::Color::parse(s),
+        crate::label::Text::HtmlStr(_) => None,
+    };
+
+    match parsed {
+        Some(c) if c.luminance().is_some_and(|luminance| luminance < 0.2) => c.lighten(0.6).into(),
+        _ => color,
+    }
+}
+
+/// Wraps `label` in a `<font>` of `color`, as an HTML label. Line breaks,
+/// and the `\n`, `\l` and `\r` sequences of an `EscStr`, become
+/// `<br/>`. Graphviz doesn't substitute `\N` and the like in HTML labels,
+/// they are kept as is.
+fn colored_label<'a>(
+    label: crate::label::Text<'a>,
+    color: std::option::Option<crate::label::Text<'a>>,
+) -> crate::label::Text<'a> {
+    let Some(
+        crate::label::Text::LabelStr(color)
+        | crate::label::Text::EscStr(color)
+        | crate::label::Text::HtmlStr(color),
+    ) = color
+    else {
+        return label;
+    };
+
+    let content = match label {
+        crate::label::Text::LabelStr(s) => crate::escape_html(&s).replace('\n', "<br/>"),
+        crate::label::Text::EscStr(s) => esc_to_html(&s),
+        crate::label::Text::HtmlStr(s) => s.into_owned(),
+    };
+
+    crate::label::Text::html(format!(
+        r#"<font color="{}">{content}</font>"#,
+        crate::escape_html(&color)
+    ))
+}
+
+/// Converts the escString `s` to HTML markup, see `colored_label`.
+fn esc_to_html(s: &str) -> String {
+    let mut html = String::with_capacity(s.len());
+    let mut text = String::new();
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        let br = match c {
+            '\n' => "<br/>",
+            '\\' => match chars.next() {
+                Some('n') => "<br/>",
+                Some('l') => r#"<br align="left"/>"#,
+                Some('r') => r#"<br align="right"/>"#,
+                Some(c @ ('\\' | '"')) => {
+                    text.push(c);
+                    continue;
+                }
+                Some(c) => {
+                    text.push('\\');
+                    text.push(c);
+                    continue;
+                }
+                None => {
+                    text.push('\\');
+                    continue;
+                }
+            },
+            c => {
+                text.push(c);
+                continue;
             }
+        };
+
+        html.push_str(&crate::escape_html(&text));
+        html.push_str(br);
+        text.clear();
+    }
+    html.push_str(&crate::escape_html(&text));
+
+    html
+}
+
+/// Counts the nodes and edges written, for `render_with_progress`.
+#[derive(Default)]
+struct Tracker<'p> {
+    progress: crate::Progress,
+    on_progress: std::option::Option<&'p mut dyn FnMut(crate::Progress)>,
+}
+
+impl Tracker<'_> {
+    fn node(&mut self) {
+        self.progress.nodes += 1;
+        self.report();
+    }
 
-            write!(w, "]")?;
+    fn edge(&mut self) {
+        self.progress.edges += 1;
+        self.report();
+    }
+
+    fn report(&mut self) {
+        if let Some(on_progress) = &mut self.on_progress {
+            on_progress(self.progress);
         }
+    }
+}
 
-        writeln!(w, ";")?;
+/// Attributes of a node or an edge, as groups of `(key, value)` pairs
+/// whose value is already in DOT syntax. A group is written between the
+/// same brackets.
+#[derive(Default)]
+struct Attrs(Vec<Vec<(String, String)>>);
+
+impl Attrs {
+    fn push<K: Into<String>, V: std::fmt::Display>(&mut self, key: K, value: V) {
+        self.push_group(vec![(key.into(), value.to_string())]);
+    }
+
+    fn push_group(&mut self, group: Vec<(String, String)>) {
+        if !group.is_empty() {
+            self.0.push(group);
+        }
+    }
+
+    fn push_opt<K: Into<String>, V: std::fmt::Display>(
+        &mut self,
+        key: K,
+        value: std::option::Option<V>,
+    ) {
+        if let Some(value) = value {
+            self.push(key, value);
+        }
+    }
+
+    /// Pushes `value` if it is finite, graphviz rejecting `NaN` and `inf`.
+    fn push_finite<K: Into<String>>(&mut self, key: K, value: std::option::Option<f64>) {
+        self.push_opt(key, value.filter(|value| value.is_finite()));
+    }
+
+    /// Sorts attributes by key with `Option::SortAttributes`, keeping the
+    /// order of duplicated keys. A group is sorted by its first key.
+    fn sort(&mut self, options: &[self::Option]) {
+        if options.contains(&self::Option::SortAttributes) {
+            self.0.sort_by(|a, b| a[0].0.cmp(&b[0].0));
+        }
+    }
+}
+
+/// Writes the attribute `key=value` between brackets, as the renderer
+/// does for nodes and edges. `value` is written as is, use `Text` or
+/// `Id` to get it escaped.
+pub fn write_attr<W, V>(w: &mut W, key: &str, value: V) -> crate::Result
+where
+    W: std::io::Write,
+    V: std::fmt::Display,
+{
+    write!(w, "[{key}={value}]")?;
+
+    Ok(())
+}
+
+/// Writes the attribute `key=value` like `write_attr`, if there is a
+/// `value`.
+pub fn write_attr_opt<W, V>(w: &mut W, key: &str, value: std::option::Option<V>) -> crate::Result
+where
+    W: std::io::Write,
+    V: std::fmt::Display,
+{
+    if let Some(value) = value {
+        write_attr(w, key, value)?;
     }
 
     Ok(())
 }
+
+/// Writes the attributes list of a node or an edge, each group in its
+/// own brackets, or one per line with `Option::WrapAttributes`.
+fn write_attrs<W: std::io::Write>(
+    w: &mut crate::indent_writer::IndentWriter<W>,
+    mut attrs: Attrs,
+    options: &[self::Option],
+) -> crate::Result {
+    if attrs.0.is_empty() {
+        return Ok(());
+    }
+
+    attrs.sort(options);
+
+    if options.contains(&self::Option::WrapAttributes) {
+        writeln!(w, "[")?;
+        w.indent();
+        for group in &attrs.0 {
+            writeln!(w, "{}", join_group(group))?;
+        }
+        w.dedent();
+        write!(w, "]")?;
+    } else {
+        for group in &attrs.0 {
+            write!(w, "[{}]", join_group(group))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn join_group(group: &[(String, String)]) -> String {
+    group
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Renders the DOT statement designating `endpoint`: a node is qualified
+/// by its `port`, a subgraph is referenced by its id, or enumerated if it
+/// has none.
+fn endpoint_id<'a, N, E, S, G>(
+    g: &'a G,
+    endpoint: crate::Result<crate::EdgeEndpoint<N, S>>,
+    port: std::option::Option<(
+        std::borrow::Cow<'a, str>,
+        std::option::Option<crate::Compass>,
+    )>,
+) -> crate::Result<String>
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+{
+    let id = match endpoint? {
+        crate::EdgeEndpoint::Node(n) => match port {
+            Some((port, compass)) => g.node_id(&n)?.with_port(port, compass).to_string(),
+            None => g.node_id(&n)?.to_string(),
+        },
+        crate::EdgeEndpoint::Subgraph(s) => {
+            if let Some(id) = g.subgraph_id(&s) {
+                format!("subgraph {id} {{}}")
+            } else {
+                let nodes = g
+                    .subgraph_nodes(&s)
+                    .iter()
+                    .map(|n| g.node_id(n).map(|id| id.to_string()))
+                    .collect::<crate::Result<Vec<_>>>()?;
+
+                format!("{{{}}}", nodes.join(" "))
+            }
+        }
+    };
+
+    Ok(id)
+}
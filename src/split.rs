@@ -0,0 +1,378 @@
+/// A graph made of a `Labeller` and a `GraphWalk` living in separate
+/// types, delegating to each, see
+/// [`render_split`](crate::render::render_split).
+pub(crate) struct Split<'g, L, K> {
+    pub(crate) labeller: &'g L,
+    pub(crate) walk: &'g K,
+}
+
+impl<'a, 'g: 'a, L, K> crate::Labeller<'a> for Split<'g, L, K>
+where
+    L: crate::Labeller<'g>,
+{
+    type Node = L::Node;
+    type Edge = L::Edge;
+    type Subgraph = L::Subgraph;
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        self.labeller.graph_id()
+    }
+
+    fn graph_label(&'a self) -> Option<crate::label::Text<'a>> {
+        self.labeller.graph_label()
+    }
+
+    fn graph_labelloc(&'a self) -> Option<char> {
+        self.labeller.graph_labelloc()
+    }
+
+    fn graph_colorscheme(&'a self) -> Option<crate::label::Text<'a>> {
+        self.labeller.graph_colorscheme()
+    }
+
+    fn graph_attributes(&'a self) -> Vec<(String, crate::label::Text<'a>)> {
+        self.labeller.graph_attributes()
+    }
+
+    fn graph_svg_id(&'a self) -> Option<String> {
+        self.labeller.graph_svg_id()
+    }
+
+    fn graph_fontsize(&'a self) -> Option<f64> {
+        self.labeller.graph_fontsize()
+    }
+
+    fn graph_comment(&'a self) -> Option<crate::label::Text<'a>> {
+        self.labeller.graph_comment()
+    }
+
+    fn node_defaults(&'a self) -> Vec<(String, crate::label::Text<'a>)> {
+        self.labeller.node_defaults()
+    }
+
+    fn edge_defaults(&'a self) -> Vec<(String, crate::label::Text<'a>)> {
+        self.labeller.edge_defaults()
+    }
+
+    fn node_id(&'a self, n: &Self::Node) -> crate::Result<crate::Id<'a>> {
+        self.labeller.node_id(n)
+    }
+
+    fn node_shape(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.labeller.node_shape(node)
+    }
+
+    fn node_label(&'a self, n: &Self::Node) -> crate::Result<crate::label::Text<'a>> {
+        self.labeller.node_label(n)
+    }
+
+    fn node_xlabel(&'a self, n: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.labeller.node_xlabel(n)
+    }
+
+    fn node_style(&'a self, n: &Self::Node) -> crate::Style {
+        self.labeller.node_style(n)
+    }
+
+    fn node_styles(&'a self, n: &Self::Node) -> crate::StyleSet {
+        self.labeller.node_styles(n)
+    }
+
+    fn node_colorscheme(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.labeller.node_colorscheme(node)
+    }
+
+    fn node_color(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.labeller.node_color(node)
+    }
+
+    fn node_fillcolor(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.labeller.node_fillcolor(node)
+    }
+
+    fn node_url(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.labeller.node_url(node)
+    }
+
+    fn node_target(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.labeller.node_target(node)
+    }
+
+    fn node_comment(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.labeller.node_comment(node)
+    }
+
+    fn node_skew(&'a self, node: &Self::Node) -> Option<f64> {
+        self.labeller.node_skew(node)
+    }
+
+    fn node_distortion(&'a self, node: &Self::Node) -> Option<f64> {
+        self.labeller.node_distortion(node)
+    }
+
+    fn node_penwidth(&'a self, node: &Self::Node) -> Option<f64> {
+        self.labeller.node_penwidth(node)
+    }
+
+    fn node_fontsize(&'a self, node: &Self::Node) -> Option<f64> {
+        self.labeller.node_fontsize(node)
+    }
+
+    fn node_fontcolor(&'a self, node: &Self::Node) -> Option<crate::label::Text<'a>> {
+        self.labeller.node_fontcolor(node)
+    }
+
+    fn node_pin(&'a self, node: &Self::Node) -> bool {
+        self.labeller.node_pin(node)
+    }
+
+    fn node_attributes(&'a self, node: &Self::Node) -> Vec<(String, crate::label::Text<'a>)> {
+        self.labeller.node_attributes(node)
+    }
+
+    fn edge_end_arrow(&'a self, e: &Self::Edge) -> crate::Arrow {
+        self.labeller.edge_end_arrow(e)
+    }
+
+    fn edge_start_arrow(&'a self, e: &Self::Edge) -> crate::Arrow {
+        self.labeller.edge_start_arrow(e)
+    }
+
+    fn edge_dir(&'a self, e: &Self::Edge) -> Option<crate::Direction> {
+        self.labeller.edge_dir(e)
+    }
+
+    fn edge_arrowsize(&'a self, e: &Self::Edge) -> Option<f64> {
+        self.labeller.edge_arrowsize(e)
+    }
+
+    fn edge_label(&'a self, e: &Self::Edge) -> crate::label::Text<'a> {
+        self.labeller.edge_label(e)
+    }
+
+    fn edge_xlabel(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_xlabel(e)
+    }
+
+    fn edge_headlabel(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_headlabel(e)
+    }
+
+    fn edge_taillabel(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_taillabel(e)
+    }
+
+    fn edge_headlabel_color(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_headlabel_color(e)
+    }
+
+    fn edge_taillabel_color(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_taillabel_color(e)
+    }
+
+    fn edge_labelfontname(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_labelfontname(e)
+    }
+
+    fn edge_labelfontsize(&'a self, e: &Self::Edge) -> Option<f64> {
+        self.labeller.edge_labelfontsize(e)
+    }
+
+    fn edge_labelfontcolor(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_labelfontcolor(e)
+    }
+
+    fn edge_style(&'a self, e: &Self::Edge) -> crate::Style {
+        self.labeller.edge_style(e)
+    }
+
+    fn edge_styles(&'a self, e: &Self::Edge) -> crate::StyleSet {
+        self.labeller.edge_styles(e)
+    }
+
+    fn edge_color(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_color(e)
+    }
+
+    fn edge_url(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_url(e)
+    }
+
+    fn edge_target(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_target(e)
+    }
+
+    fn edge_comment(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_comment(e)
+    }
+
+    fn edge_tooltip(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_tooltip(e)
+    }
+
+    fn edge_labeltooltip(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_labeltooltip(e)
+    }
+
+    fn edge_headtooltip(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_headtooltip(e)
+    }
+
+    fn edge_tailtooltip(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_tailtooltip(e)
+    }
+
+    fn edge_attributes(&'a self, e: &Self::Edge) -> Vec<(String, crate::label::Text<'a>)> {
+        self.labeller.edge_attributes(e)
+    }
+
+    fn edge_weight(&'a self, e: &Self::Edge) -> Option<f64> {
+        self.labeller.edge_weight(e)
+    }
+
+    fn edge_weight_int(&'a self, e: &Self::Edge) -> Option<i64> {
+        self.labeller.edge_weight_int(e)
+    }
+
+    fn edge_constraint(&'a self, e: &Self::Edge) -> bool {
+        self.labeller.edge_constraint(e)
+    }
+
+    fn edge_len(&'a self, e: &Self::Edge) -> Option<f64> {
+        self.labeller.edge_len(e)
+    }
+
+    fn edge_fontsize(&'a self, e: &Self::Edge) -> Option<f64> {
+        self.labeller.edge_fontsize(e)
+    }
+
+    fn edge_fontcolor(&'a self, e: &Self::Edge) -> Option<crate::label::Text<'a>> {
+        self.labeller.edge_fontcolor(e)
+    }
+
+    fn edge_penwidth(&'a self, e: &Self::Edge) -> Option<f64> {
+        self.labeller.edge_penwidth(e)
+    }
+
+    fn edge_source_port(
+        &'a self,
+        e: &Self::Edge,
+    ) -> Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)> {
+        self.labeller.edge_source_port(e)
+    }
+
+    fn edge_target_port(
+        &'a self,
+        e: &Self::Edge,
+    ) -> Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)> {
+        self.labeller.edge_target_port(e)
+    }
+
+    fn subgraph_id(&'a self, s: &Self::Subgraph) -> Option<crate::Id<'a>> {
+        self.labeller.subgraph_id(s)
+    }
+
+    fn subgraph_label(&'a self, s: &Self::Subgraph) -> crate::label::Text<'a> {
+        self.labeller.subgraph_label(s)
+    }
+
+    fn subgraph_style(&'a self, s: &Self::Subgraph) -> crate::Style {
+        self.labeller.subgraph_style(s)
+    }
+
+    fn subgraph_shape(&'a self, s: &Self::Subgraph) -> Option<crate::label::Text<'a>> {
+        self.labeller.subgraph_shape(s)
+    }
+
+    fn subgraph_color(&'a self, s: &Self::Subgraph) -> Option<crate::label::Text<'a>> {
+        self.labeller.subgraph_color(s)
+    }
+
+    fn subgraph_fontsize(&'a self, s: &Self::Subgraph) -> Option<f64> {
+        self.labeller.subgraph_fontsize(s)
+    }
+
+    fn kind(&self) -> crate::Kind {
+        self.labeller.kind()
+    }
+
+    fn strict(&self) -> bool {
+        self.labeller.strict()
+    }
+}
+
+impl<'a, 'g: 'a, L, K> crate::GraphWalk<'a> for Split<'g, L, K>
+where
+    L: crate::Labeller<'g>,
+    K: crate::GraphWalk<'g>,
+{
+    type Node = K::Node;
+    type Edge = K::Edge;
+    type Subgraph = K::Subgraph;
+
+    fn nodes(&'a self) -> crate::Nodes<'a, Self::Node> {
+        self.walk.nodes()
+    }
+
+    fn is_empty(&'a self) -> bool {
+        self.walk.is_empty()
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, Self::Edge> {
+        self.walk.edges()
+    }
+
+    fn edges_iter(&'a self) -> Box<dyn Iterator<Item = Self::Edge> + 'a> {
+        self.walk.edges_iter()
+    }
+
+    fn source(&'a self, edge: &Self::Edge) -> Self::Node {
+        self.walk.source(edge)
+    }
+
+    fn target(&'a self, edge: &Self::Edge) -> Self::Node {
+        self.walk.target(edge)
+    }
+
+    fn source_endpoint(
+        &'a self,
+        edge: &Self::Edge,
+    ) -> crate::Result<crate::EdgeEndpoint<Self::Node, Self::Subgraph>> {
+        self.walk.source_endpoint(edge)
+    }
+
+    fn target_endpoint(
+        &'a self,
+        edge: &Self::Edge,
+    ) -> crate::Result<crate::EdgeEndpoint<Self::Node, Self::Subgraph>> {
+        self.walk.target_endpoint(edge)
+    }
+
+    fn subgraphs(&'a self) -> crate::Subgraphs<'a, Self::Subgraph> {
+        self.walk.subgraphs()
+    }
+
+    fn subgraph_nodes(&'a self, s: &Self::Subgraph) -> crate::Nodes<'a, Self::Node> {
+        self.walk.subgraph_nodes(s)
+    }
+
+    fn subgraph_edges(&'a self, s: &Self::Subgraph) -> crate::Edges<'a, Self::Edge> {
+        self.walk.subgraph_edges(s)
+    }
+
+    fn subgraph_children(&'a self, s: &Self::Subgraph) -> crate::Subgraphs<'a, Self::Subgraph> {
+        self.walk.subgraph_children(s)
+    }
+
+    fn subgraph_rank(&'a self, s: &Self::Subgraph) -> Option<crate::Rank> {
+        self.walk.subgraph_rank(s)
+    }
+
+    fn rank_order(&'a self, s: &Self::Subgraph) -> crate::Nodes<'a, Self::Node> {
+        self.walk.rank_order(s)
+    }
+
+    fn node_rank(&'a self, n: &Self::Node) -> Option<i32> {
+        self.walk.node_rank(n)
+    }
+}
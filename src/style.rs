@@ -3,6 +3,8 @@
 /// Note that some of these are not valid for edges.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum Style {
+    /// No style: the `style` attribute is left unset, so graphviz uses
+    /// its default.
     None,
     Solid,
     Dashed,
@@ -16,6 +18,18 @@ pub enum Style {
     Invisible,
 }
 
+impl Style {
+    /// Return `true` if graphviz accepts this style on edges, the others
+    /// are only meaningful for nodes and clusters.
+    #[must_use]
+    pub fn is_edge_style(self) -> bool {
+        matches!(
+            self,
+            Self::None | Self::Solid | Self::Dashed | Self::Dotted | Self::Bold | Self::Invisible
+        )
+    }
+}
+
 impl std::fmt::Display for Style {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -35,3 +49,47 @@ impl std::fmt::Display for Style {
         write!(f, "{s}")
     }
 }
+
+/// A combination of styles, rendered as a comma separated list like
+/// `filled,bold`. `Style::None` entries are skipped.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct StyleSet {
+    pub styles: Vec<Style>,
+}
+
+impl StyleSet {
+    /// Return `true` if there is no style to render.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.styles.iter().all(|style| *style == Style::None)
+    }
+}
+
+impl std::fmt::Display for StyleSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let styles = self
+            .styles
+            .iter()
+            .filter(|style| **style != Style::None)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", styles.join(","))
+    }
+}
+
+impl From<Style> for StyleSet {
+    fn from(style: Style) -> Self {
+        Self {
+            styles: vec![style],
+        }
+    }
+}
+
+impl FromIterator<Style> for StyleSet {
+    fn from_iter<I: IntoIterator<Item = Style>>(iter: I) -> Self {
+        Self {
+            styles: iter.into_iter().collect(),
+        }
+    }
+}
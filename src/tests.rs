@@ -273,9 +273,103 @@ impl<'a> crate::GraphWalk<'a> for LabelledGraphWithEscStrs {
     }
 }
 
+/// Wraps a `LabelledGraph`, delegating identifiers, edges attributes and
+/// the whole walk to it, so that a test only has to write the `Labeller`
+/// (and optionally `GraphWalk`) methods it exercises.
+macro_rules! labelled_graph {
+    ($name:ident { $($body:tt)* }) => {
+        labelled_graph!($name { $($body)* } {});
+    };
+    ($name:ident { $($body:tt)* } { $($walk:tt)* }) => {
+        struct $name(LabelledGraph);
+
+        impl<'a> crate::Labeller<'a> for $name {
+            type Node = Node;
+            type Edge = &'a Edge;
+            type Subgraph = Subgraph;
+
+            fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+                self.0.graph_id()
+            }
+
+            fn node_id(&'a self, n: &Node) -> crate::Result<crate::Id<'a>> {
+                self.0.node_id(n)
+            }
+
+            fn edge_label(&'a self, e: &&'a Edge) -> crate::label::Text<'a> {
+                self.0.edge_label(e)
+            }
+
+            fn edge_style(&'a self, e: &&'a Edge) -> crate::Style {
+                self.0.edge_style(e)
+            }
+
+            fn edge_color(&'a self, e: &&'a Edge) -> Option<crate::label::Text<'a>> {
+                self.0.edge_color(e)
+            }
+
+            fn edge_start_arrow(&'a self, e: &&'a Edge) -> crate::Arrow {
+                self.0.edge_start_arrow(e)
+            }
+
+            fn edge_end_arrow(&'a self, e: &&'a Edge) -> crate::Arrow {
+                self.0.edge_end_arrow(e)
+            }
+
+            $($body)*
+        }
+
+        impl<'a> crate::GraphWalk<'a> for $name {
+            type Node = Node;
+            type Edge = &'a Edge;
+            type Subgraph = Subgraph;
+
+            fn nodes(&'a self) -> crate::Nodes<'a, Node> {
+                self.0.nodes()
+            }
+
+            fn edges(&'a self) -> crate::Edges<'a, &'a Edge> {
+                self.0.edges()
+            }
+
+            fn source(&'a self, edge: &&'a Edge) -> Node {
+                self.0.source(edge)
+            }
+
+            fn target(&'a self, edge: &&'a Edge) -> Node {
+                self.0.target(edge)
+            }
+
+            fn subgraphs(&'a self) -> crate::Subgraphs<'a, Subgraph> {
+                self.0.subgraphs()
+            }
+
+            fn subgraph_nodes(&'a self, s: &Subgraph) -> crate::Nodes<'a, Node> {
+                self.0.subgraph_nodes(s)
+            }
+
+            $($walk)*
+        }
+    };
+}
+
 fn test_input(g: LabelledGraph) -> crate::Result<String> {
+    test_input_opts(&g, &[])
+}
+
+fn test_input_opts<'a, N, E, S, G>(
+    g: &'a G,
+    options: &[crate::render::Option],
+) -> crate::Result<String>
+where
+    N: Clone + 'a,
+    E: Clone + 'a,
+    S: Clone + 'a,
+    G: crate::Labeller<'a, Node = N, Edge = E, Subgraph = S>
+        + crate::GraphWalk<'a, Node = N, Edge = E, Subgraph = S>,
+{
     let mut writer = Vec::new();
-    crate::render(&g, &mut writer)?;
+    crate::render_opts(g, &mut writer, options)?;
 
     let mut s = String::new();
     std::io::Read::read_to_string(&mut &*writer, &mut s)?;
@@ -347,6 +441,29 @@ fn single_node_with_style() {
     );
 }
 
+#[test]
+fn single_node_with_invisible_style() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let styles = Some(vec![crate::Style::Invisible, crate::Style::None]);
+    let r = test_input(LabelledGraph::new(
+        "invisible",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::Invisible, None)],
+        vec![],
+        styles,
+    ));
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph invisible {
+    N0[label="N0"][style="invis"];
+    N1[label="N1"];
+    N0 -> N1[label="E"][style="invis"];
+}
+"#
+    );
+}
+
 #[test]
 fn single_edge() {
     let labels: Trivial = NodeLabels::UnlabelledNodes(2);
@@ -484,12 +601,16 @@ fn left_aligned_text() {
 
     let mut writer = Vec::new();
 
-    let g = LabelledGraphWithEscStrs::new("syntax_tree", labels, vec![
-        edge(0, 1, "then", crate::Style::None, None),
-        edge(0, 2, "else", crate::Style::None, None),
-        edge(1, 3, ";", crate::Style::None, None),
-        edge(2, 3, ";", crate::Style::None, None),
-    ]);
+    let g = LabelledGraphWithEscStrs::new(
+        "syntax_tree",
+        labels,
+        vec![
+            edge(0, 1, "then", crate::Style::None, None),
+            edge(0, 2, "else", crate::Style::None, None),
+            edge(1, 3, ";", crate::Style::None, None),
+            edge(2, 3, ";", crate::Style::None, None),
+        ],
+    );
 
     crate::render(&g, &mut writer).unwrap();
     let mut r = String::new();
@@ -616,18 +737,15 @@ fn subgraph() {
         r#"digraph di {
     subgraph cluster_0 {
         label="";
-
         N0;
         N1;
     }
 
     subgraph cluster_1 {
         label="";
-
         N2;
         N3;
     }
-
     N0[label="{x,y}"];
     N1[label="{x}"];
     N2[label="{y}"];
@@ -640,3 +758,3935 @@ fn subgraph() {
 "#
     );
 }
+
+labelled_graph!(WeightedGraph {
+    fn edge_weight(&'a self, e: &&'a Edge) -> Option<f64> {
+        Some(e.to as f64)
+    }
+});
+
+#[test]
+fn edges_sorted_by_weight() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(4);
+    let g = WeightedGraph(LabelledGraph::new(
+        "weights",
+        labels,
+        vec![
+            edge(0, 1, "light", crate::Style::None, None),
+            edge(0, 3, "heavy", crate::Style::None, None),
+            edge(0, 2, "medium", crate::Style::None, None),
+        ],
+        vec![],
+        None,
+    ));
+
+    let r = test_input_opts(&g, &[crate::render::Option::SortEdgesByWeight]);
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph weights {
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N3[label="N3"];
+    N0 -> N3[label="heavy"][weight=3];
+    N0 -> N2[label="medium"][weight=2];
+    N0 -> N1[label="light"][weight=1];
+}
+"#
+    );
+}
+
+#[test]
+fn esc_str_escaping() {
+    assert_eq!(EscStr(r"left\l".into()).to_string(), r#""left\l""#);
+    assert_eq!(EscStr("two\nlines".into()).to_string(), r#""two\nlines""#);
+    assert_eq!(
+        EscStr(r"back\\slash".into()).to_string(),
+        r#""back\\slash""#
+    );
+    assert_eq!(EscStr("\"quoted\"".into()).to_string(), r#""\"quoted\"""#);
+    assert_eq!(EscStr("tab\tcafé".into()).to_string(), "\"tab\tcafé\"");
+}
+
+#[test]
+fn sfdp_attributes() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("sfdp", labels, vec![], vec![], None);
+
+    let r = test_input_opts(
+        &g,
+        &[
+            crate::render::Option::Quadtree(true),
+            crate::render::Option::OverlapScaling(crate::Float(-4.)),
+        ],
+    );
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph sfdp {
+    graph[quadtree=true overlap_scaling=-4];
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+#[test]
+fn subgraph_without_stray_blank_lines() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(3);
+    let r = test_input(LabelledGraph::new(
+        "di",
+        labels,
+        vec![],
+        vec![vec![0], vec![1], vec![2]],
+        None,
+    ))
+    .unwrap();
+
+    assert!(!r.contains("\n\n\n"));
+    assert!(!r.contains("{\n\n"));
+    assert!(!r.contains("\n\n    N0[label"));
+}
+
+#[test]
+fn fontnames() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("fontnames", labels, vec![], vec![], None);
+
+    let r = test_input_opts(
+        &g,
+        &[crate::render::Option::Fontnames(crate::Fontnames::Svg)],
+    );
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph fontnames {
+    graph[fontnames="svg"];
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+#[test]
+fn text_into_owned() {
+    let text: crate::label::Text<'static> = {
+        let label = String::from("borrowed");
+        crate::label::Text::label(&label[..]).into_owned()
+    };
+
+    assert_eq!(text.to_string(), r#""borrowed""#);
+}
+
+#[test]
+fn id_into_owned() {
+    let id: crate::Id<'static> = {
+        let name = String::from("borrowed");
+        crate::Id::new(&name[..]).unwrap().into_owned()
+    };
+
+    assert_eq!(id.to_string(), "borrowed");
+}
+
+labelled_graph!(HtmlGraph {
+    fn node_label(&'a self, _n: &Node) -> crate::Result<crate::label::Text<'a>> {
+        Ok(crate::label::Text::html("<b>bold</b>"))
+    }
+});
+
+#[test]
+fn html_label_shape() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = HtmlGraph(LabelledGraph::new("html", labels, vec![], vec![], None));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph html {
+    N0[label=<<b>bold</b>>][shape=none];
+}
+"#
+    );
+
+    assert_eq!(
+        test_input_opts(&g, &[crate::render::Option::NoHtmlShape]).unwrap(),
+        r#"digraph html {
+    N0[label=<<b>bold</b>>];
+}
+"#
+    );
+}
+
+labelled_graph!(EndpointGraph {
+    fn subgraph_id(&'a self, s: &Subgraph) -> Option<crate::Id<'a>> {
+        self.0.subgraph_id(s)
+    }
+} {
+    fn source_endpoint(
+        &'a self,
+        e: &&'a Edge,
+    ) -> crate::Result<crate::EdgeEndpoint<Node, Subgraph>> {
+        match e.label {
+            "cluster" => Ok(crate::EdgeEndpoint::Subgraph(e.from)),
+            "invalid" => Err(crate::Error::InvalidId),
+            _ => Ok(crate::EdgeEndpoint::Node(e.from)),
+        }
+    }
+});
+
+#[test]
+fn subgraph_endpoint() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(3);
+    let g = EndpointGraph(LabelledGraph::new(
+        "di",
+        labels,
+        vec![
+            edge(0, 2, "cluster", crate::Style::None, None),
+            edge(1, 2, "node", crate::Style::None, None),
+        ],
+        vec![vec![0, 1]],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph di {
+    subgraph cluster_0 {
+        label="";
+        N0;
+        N1;
+    }
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    subgraph cluster_0 {} -> N2[label="cluster"];
+    N1 -> N2[label="node"];
+}
+"#
+    );
+}
+
+#[test]
+fn endpoint_error() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = EndpointGraph(LabelledGraph::new(
+        "di",
+        labels,
+        vec![edge(0, 1, "invalid", crate::Style::None, None)],
+        vec![],
+        None,
+    ));
+
+    assert!(matches!(
+        test_input_opts(&g, &[]),
+        Err(crate::Error::InvalidId)
+    ));
+
+    let view = crate::InducedSubgraph::new(&g, |_: &Node| true);
+    let mut writer = Vec::new();
+
+    assert!(matches!(
+        crate::render(&view, &mut writer),
+        Err(crate::Error::InvalidId)
+    ));
+}
+
+#[test]
+fn render_all() {
+    let first = LabelledGraph::new(
+        "first",
+        NodeLabels::UnlabelledNodes(1),
+        vec![],
+        vec![],
+        None,
+    );
+    let second = LabelledGraph::new(
+        "second",
+        NodeLabels::UnlabelledNodes(1),
+        vec![],
+        vec![],
+        None,
+    );
+
+    let mut writer = Vec::new();
+    crate::render_all(&[&first, &second], &mut writer, &[]).unwrap();
+
+    assert_eq!(
+        String::from_utf8(writer).unwrap(),
+        r#"digraph first {
+    N0[label="N0"];
+}
+
+digraph second {
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+#[test]
+fn style_set_without_dangling_comma() {
+    let styles: crate::StyleSet = [crate::Style::Filled, crate::Style::None, crate::Style::Bold]
+        .into_iter()
+        .collect();
+    assert_eq!(styles.to_string(), "filled,bold");
+
+    let styles: crate::StyleSet = [crate::Style::Filled, crate::Style::None]
+        .into_iter()
+        .collect();
+    assert_eq!(styles.to_string(), "filled");
+
+    assert!(crate::StyleSet::from(crate::Style::None).is_empty());
+}
+
+#[derive(Default)]
+struct Counter {
+    nodes: usize,
+    edges: usize,
+    subgraphs: usize,
+}
+
+impl<E, S> crate::Visitor<Node, E, S> for Counter {
+    fn visit_node(&mut self, _node: &Node) {
+        self.nodes += 1;
+    }
+
+    fn visit_edge(&mut self, _edge: &E) {
+        self.edges += 1;
+    }
+
+    fn visit_subgraph(&mut self, _subgraph: &S) {
+        self.subgraphs += 1;
+    }
+}
+
+#[test]
+fn visitor() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(3);
+    let g = LabelledGraph::new(
+        "visitor",
+        labels,
+        vec![
+            edge(0, 1, "", crate::Style::None, None),
+            edge(1, 2, "", crate::Style::None, None),
+        ],
+        vec![vec![0, 1]],
+        None,
+    );
+
+    let mut counter = Counter::default();
+    crate::walk(&g, &mut counter);
+
+    assert_eq!(counter.nodes, 3);
+    assert_eq!(counter.edges, 2);
+    assert_eq!(counter.subgraphs, 1);
+}
+
+labelled_graph!(AttributesGraph {
+    fn node_style(&'a self, _n: &Node) -> crate::Style {
+        crate::Style::Filled
+    }
+
+    fn node_color(&'a self, _n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("red".into()))
+    }
+
+    fn node_shape(&'a self, _n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("box".into()))
+    }
+
+    fn edge_weight(&'a self, _e: &&'a Edge) -> Option<f64> {
+        Some(2.)
+    }
+});
+
+#[test]
+fn wrapped_attributes() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = AttributesGraph(LabelledGraph::new(
+        "wrapped",
+        labels,
+        vec![edge_with_arrows(
+            0,
+            1,
+            "E",
+            crate::Style::Bold,
+            crate::Arrow::default(),
+            crate::Arrow::normal(),
+            Some("blue"),
+        )],
+        vec![],
+        None,
+    ));
+
+    let r = test_input_opts(&g, &[crate::render::Option::WrapAttributes]);
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph wrapped {
+    N0[
+        label="N0"
+        style="filled"
+        color="red"
+        shape="box"
+    ];
+    N1[
+        label="N1"
+        style="filled"
+        color="red"
+        shape="box"
+    ];
+    N0 -> N1[
+        label="E"
+        style="bold"
+        color="blue"
+        weight=2
+        arrowhead="normal"
+    ];
+}
+"#
+    );
+}
+
+#[test]
+fn pack() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = LabelledGraph::new("pack", labels, vec![], vec![], None);
+
+    let r = test_input_opts(
+        &g,
+        &[
+            crate::render::Option::Pack(true),
+            crate::render::Option::Packmode(crate::Packmode::Clust),
+        ],
+    );
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph pack {
+    graph[pack=true packmode="clust"];
+    N0[label="N0"];
+    N1[label="N1"];
+}
+"#
+    );
+}
+
+labelled_graph!(EdgeStylesGraph {
+    fn edge_styles(&'a self, e: &&'a Edge) -> crate::StyleSet {
+        [e.style, crate::Style::Dotted].into_iter().collect()
+    }
+});
+
+#[test]
+fn edge_styles() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = EdgeStylesGraph(LabelledGraph::new(
+        "edge_styles",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::Bold, None)],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[crate::render::Option::Validate]).unwrap(),
+        r#"digraph edge_styles {
+    N0[label="N0"];
+    N1[label="N1"];
+    N0 -> N1[label="E"][style="bold,dotted"];
+}
+"#
+    );
+}
+
+#[test]
+fn validate_edge_styles() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = EdgeStylesGraph(LabelledGraph::new(
+        "edge_styles",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::Filled, None)],
+        vec![],
+        None,
+    ));
+
+    assert!(test_input_opts(&g, &[]).is_ok());
+    assert!(matches!(
+        test_input_opts(&g, &[crate::render::Option::Validate]),
+        Err(crate::Error::InvalidEdgeStyle(crate::Style::Filled))
+    ));
+}
+
+labelled_graph!(ArrowsizeGraph {
+    fn edge_arrowsize(&'a self, _e: &&'a Edge) -> Option<f64> {
+        Some(1.5)
+    }
+});
+
+#[test]
+fn arrowsize() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = ArrowsizeGraph(LabelledGraph::new(
+        "arrowsize",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph arrowsize {
+    N0[label="N0"];
+    N1[label="N1"];
+    N0 -> N1[label="E"][arrowsize=1.5];
+}
+"#
+    );
+}
+
+labelled_graph!(HtmlCircleGraph {
+    fn node_label(&'a self, _n: &Node) -> crate::Result<crate::label::Text<'a>> {
+        Ok(crate::label::Text::html("<b>bold</b>"))
+    }
+
+    fn node_shape(&'a self, _n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("circle".into()))
+    }
+});
+
+#[test]
+fn validate_html_shape() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = HtmlCircleGraph(LabelledGraph::new("html", labels, vec![], vec![], None));
+
+    assert!(test_input_opts(&g, &[]).is_ok());
+    assert!(matches!(
+        test_input_opts(&g, &[crate::render::Option::Validate]),
+        Err(crate::Error::IncompatibleHtmlShape(shape)) if shape == "circle"
+    ));
+}
+
+labelled_graph!(LinkGraph {
+    fn node_url(&'a self, n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr(format!("https://example.org/{n}").into()))
+    }
+
+    fn node_target(&'a self, _n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("_blank".into()))
+    }
+
+    fn edge_url(&'a self, _e: &&'a Edge) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("https://example.org/edge".into()))
+    }
+
+    fn edge_target(&'a self, _e: &&'a Edge) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("_blank".into()))
+    }
+});
+
+#[test]
+fn link_target() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = LinkGraph(LabelledGraph::new(
+        "links",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph links {
+    N0[label="N0"][URL="https://example.org/0"][target="_blank"];
+    N1[label="N1"][URL="https://example.org/1"][target="_blank"];
+    N0 -> N1[label="E"][URL="https://example.org/edge"][target="_blank"];
+}
+"#
+    );
+}
+
+#[test]
+fn render_with_warnings() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = LabelledGraph::new(
+        "warnings",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::Filled, None)],
+        vec![],
+        None,
+    );
+
+    let mut writer = Vec::new();
+    let warnings = crate::render_with_warnings(&g, &mut writer, &[]).unwrap();
+
+    assert_eq!(
+        String::from_utf8(writer).unwrap(),
+        r#"digraph warnings {
+    N0[label="N0"];
+    N1[label="N1"];
+    N0 -> N1[label="E"][style="filled"];
+}
+"#
+    );
+    assert_eq!(
+        warnings,
+        vec![crate::Warning::NodeStyleOnEdge(crate::Style::Filled)]
+    );
+}
+
+#[test]
+fn escape_html_attr() {
+    assert_eq!(crate::escape_html("it's"), "it's");
+    assert_eq!(
+        crate::escape_html_attr("it's <a & \"b\">"),
+        "it&#39;s &lt;a &amp; &quot;b&quot;&gt;"
+    );
+    assert_eq!(
+        crate::escape_html_attr("a\tb\nc\u{0}\u{1b}d"),
+        "a&#9;b&#10;cd"
+    );
+}
+
+#[test]
+fn html_escaped() {
+    assert_eq!(
+        crate::label::Text::html_escaped("a & b").to_string(),
+        "<a &amp; b>"
+    );
+    assert_eq!(
+        crate::label::Text::html("a &amp; b").to_string(),
+        "<a &amp; b>"
+    );
+}
+
+#[test]
+fn neato_mode() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("neato", labels, vec![], vec![], None);
+
+    let r = test_input_opts(
+        &g,
+        &[
+            crate::render::Option::NeatoMode(crate::NeatoMode::Sgd),
+            crate::render::Option::Sep(crate::Float(0.5)),
+            crate::render::Option::Esep(crate::Float(0.25)),
+        ],
+    );
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph neato {
+    graph[mode="sgd" sep=0.5 esep=0.25];
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+labelled_graph!(XlabelGraph {
+    fn node_xlabel(&'a self, n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr(format!("x{n}").into()))
+    }
+
+    fn edge_xlabel(&'a self, _e: &&'a Edge) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("xE".into()))
+    }
+});
+
+#[test]
+fn force_xlabels() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = XlabelGraph(LabelledGraph::new(
+        "xlabels",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    ));
+
+    let r = test_input_opts(&g, &[crate::render::Option::ForceLabels]);
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph xlabels {
+    graph[forcelabels=true];
+    N0[label="N0"][xlabel="x0"];
+    N1[label="N1"][xlabel="x1"];
+    N0 -> N1[label="E"][xlabel="xE"];
+}
+"#
+    );
+}
+
+#[test]
+fn dot_limits() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("limits", labels, vec![], vec![], None);
+
+    let r = test_input_opts(
+        &g,
+        &[
+            crate::render::Option::Nslimit(crate::Float(0.5)),
+            crate::render::Option::Mclimit(crate::Float(2.)),
+        ],
+    );
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph limits {
+    graph[nslimit=0.5 mclimit=2.0];
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+#[test]
+fn id_validation_modes() {
+    assert!(crate::Id::new_validated("node_1", crate::IdMode::Strict).is_ok());
+    assert!(matches!(
+        crate::Id::new_validated("a.b", crate::IdMode::Strict),
+        Err(crate::Error::InvalidId)
+    ));
+
+    let quoted = crate::Id::new_validated(r#"a.b "c"\d"#, crate::IdMode::Quoted).unwrap();
+    assert_eq!(quoted.to_string(), r#""a.b \"c\"\\d""#);
+
+    let html = crate::Id::new_validated("<b>bold</b>", crate::IdMode::Html).unwrap();
+    assert_eq!(html.to_string(), "<<b>bold</b>>");
+    assert!(matches!(
+        crate::Id::new_validated("<b>bold</b", crate::IdMode::Html),
+        Err(crate::Error::UnbalancedHtml)
+    ));
+    assert!(matches!(
+        crate::Id::new_validated("b>", crate::IdMode::Html),
+        Err(crate::Error::UnbalancedHtml)
+    ));
+}
+
+labelled_graph!(LayoutGraph {
+    fn graph_attributes(&'a self) -> Vec<(String, crate::label::Text<'a>)> {
+        vec![("bb".to_string(), LabelStr("0,0,100,100".into()))]
+    }
+
+    fn node_attributes(&'a self, n: &Node) -> Vec<(String, crate::label::Text<'a>)> {
+        vec![("pos".to_string(), LabelStr(format!("{},{}!", n * 50, n * 50).into()))]
+    }
+
+    fn edge_attributes(&'a self, _e: &&'a Edge) -> Vec<(String, crate::label::Text<'a>)> {
+        vec![("pos".to_string(), LabelStr("e,50,50 0,0 25,25 50,50".into()))]
+    }
+});
+
+#[test]
+fn layout_passthrough() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = LayoutGraph(LabelledGraph::new(
+        "layout",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph layout {
+    graph[bb="0,0,100,100"];
+    N0[label="N0"][pos="0,0!"];
+    N1[label="N1"][pos="50,50!"];
+    N0 -> N1[label="E"][pos="e,50,50 0,0 25,25 50,50"];
+}
+"#
+    );
+}
+
+struct BadIdGraph(LabelledGraph);
+
+impl<'a> crate::Labeller<'a> for BadIdGraph {
+    type Node = Node;
+    type Edge = &'a Edge;
+    type Subgraph = Subgraph;
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        self.0.graph_id()
+    }
+
+    fn node_id(&'a self, n: &Node) -> crate::Result<crate::Id<'a>> {
+        crate::Id::new(format!("bad id {n}"))
+    }
+}
+
+impl<'a> crate::GraphWalk<'a> for BadIdGraph {
+    type Node = Node;
+    type Edge = &'a Edge;
+    type Subgraph = Subgraph;
+
+    fn nodes(&'a self) -> crate::Nodes<'a, Node> {
+        self.0.nodes()
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, &'a Edge> {
+        self.0.edges()
+    }
+
+    fn source(&'a self, edge: &&'a Edge) -> Node {
+        edge.from
+    }
+
+    fn target(&'a self, edge: &&'a Edge) -> Node {
+        edge.to
+    }
+}
+
+#[test]
+fn validate() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("valid", labels, vec![], vec![], None);
+    assert!(crate::validate(&g, &[]).is_ok());
+
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = BadIdGraph(LabelledGraph::new("invalid", labels, vec![], vec![], None));
+    assert!(matches!(
+        crate::validate(&g, &[]),
+        Err(crate::Error::InvalidId)
+    ));
+
+    // Only rejected once `Option::Validate` is turned on.
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = LabelledGraph::new(
+        "filled_edge",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::Filled, None)],
+        vec![],
+        None,
+    );
+    assert!(test_input_opts(&g, &[]).is_ok());
+    assert!(matches!(
+        crate::validate(&g, &[]),
+        Err(crate::Error::InvalidEdgeStyle(crate::Style::Filled))
+    ));
+}
+
+#[test]
+fn rotate() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("landscape", labels, vec![], vec![], None);
+
+    let r = test_input_opts(&g, &[crate::render::Option::Rotate(90)]);
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph landscape {
+    graph[rotate=90];
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+/// Adjacency lists, walked without ever building an edges slice.
+struct AdjacencyGraph(Vec<Vec<Node>>);
+
+impl<'a> crate::Labeller<'a> for AdjacencyGraph {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = ();
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        crate::Id::new("adjacency")
+    }
+
+    fn node_id(&'a self, n: &Node) -> crate::Result<crate::Id<'a>> {
+        id_name(n)
+    }
+}
+
+impl<'a> crate::GraphWalk<'a> for AdjacencyGraph {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = ();
+
+    fn nodes(&'a self) -> crate::Nodes<'a, Node> {
+        (0..self.0.len()).collect()
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, (Node, Node)> {
+        self.edges_iter().collect()
+    }
+
+    fn edges_iter(&'a self) -> Box<dyn Iterator<Item = (Node, Node)> + 'a> {
+        Box::new(
+            self.0
+                .iter()
+                .enumerate()
+                .flat_map(|(from, targets)| targets.iter().map(move |to| (from, *to))),
+        )
+    }
+
+    fn source(&'a self, edge: &(Node, Node)) -> Node {
+        edge.0
+    }
+
+    fn target(&'a self, edge: &(Node, Node)) -> Node {
+        edge.1
+    }
+}
+
+#[test]
+fn edges_iter() {
+    let g = AdjacencyGraph(vec![vec![1, 2], vec![2], vec![]]);
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph adjacency {
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N0 -> N1[label=""];
+    N0 -> N2[label=""];
+    N1 -> N2[label=""];
+}
+"#
+    );
+    assert_eq!(crate::GraphWalk::edges(&g).len(), 3);
+}
+
+struct EdgesOnlyGraph(Vec<(Node, Node)>);
+
+impl<'a> crate::Labeller<'a> for EdgesOnlyGraph {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = ();
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        crate::Id::new("edges_only")
+    }
+
+    fn node_id(&'a self, n: &Node) -> crate::Result<crate::Id<'a>> {
+        id_name(n)
+    }
+}
+
+impl<'a> crate::GraphWalk<'a> for EdgesOnlyGraph {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = ();
+
+    fn nodes(&'a self) -> crate::Nodes<'a, Node> {
+        (0..3).collect()
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, (Node, Node)> {
+        std::borrow::Cow::Borrowed(&self.0)
+    }
+
+    fn source(&'a self, edge: &(Node, Node)) -> Node {
+        edge.0
+    }
+
+    fn target(&'a self, edge: &(Node, Node)) -> Node {
+        edge.1
+    }
+}
+
+#[test]
+fn edges_iter_default() {
+    let g = EdgesOnlyGraph(vec![(0, 1), (1, 2)]);
+
+    assert_eq!(
+        crate::GraphWalk::edges_iter(&g).collect::<Vec<_>>(),
+        vec![(0, 1), (1, 2)]
+    );
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph edges_only {
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N0 -> N1[label=""];
+    N1 -> N2[label=""];
+}
+"#
+    );
+}
+
+labelled_graph!(DefaultsGraph {
+    fn node_defaults(&'a self) -> Vec<(String, crate::label::Text<'a>)> {
+        vec![("shape".to_string(), LabelStr("box".into()))]
+    }
+
+    fn edge_defaults(&'a self) -> Vec<(String, crate::label::Text<'a>)> {
+        vec![("color".to_string(), LabelStr("gray".into()))]
+    }
+});
+
+#[test]
+fn node_and_edge_defaults() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = DefaultsGraph(LabelledGraph::new(
+        "defaults",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    ));
+
+    let r = test_input_opts(
+        &g,
+        &[crate::render::Option::Fontname("Courier".to_string())],
+    );
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph defaults {
+    graph[fontname="Courier"];
+    node[fontname="Courier" shape="box"];
+    edge[fontname="Courier" color="gray"];
+    N0[label="N0"];
+    N1[label="N1"];
+    N0 -> N1[label="E"];
+}
+"#
+    );
+}
+
+#[test]
+fn arrow_spacer() {
+    let arrow = crate::Arrow::from([
+        crate::arrow::Shape::normal(),
+        crate::arrow::Shape::spacer(),
+        crate::arrow::Shape::tee(),
+    ]);
+
+    assert_eq!(arrow.to_string(), "normalnonetee");
+    assert!(arrow.to_string().parse::<crate::Arrow>().unwrap() == arrow);
+}
+
+#[test]
+fn options_from_vars() {
+    let vars = |name: &str| match name {
+        "DOT2_THEME" => Some("dark".to_string()),
+        "DOT2_FONT" => Some("Courier".to_string()),
+        _ => None,
+    };
+
+    assert_eq!(
+        crate::render::Option::from_vars(vars),
+        vec![
+            crate::render::Option::DarkTheme,
+            crate::render::Option::Fontname("Courier".to_string()),
+        ]
+    );
+
+    let vars = |name: &str| (name == "DOT2_THEME").then(|| "light".to_string());
+
+    assert_eq!(crate::render::Option::from_vars(vars), vec![]);
+    assert_eq!(crate::render::Option::from_vars(|_| None), vec![]);
+
+    let vars = |name: &str| (name == "DOT2_FONT").then(String::new);
+
+    assert_eq!(crate::render::Option::from_vars(vars), vec![]);
+}
+
+labelled_graph!(RankOrderGraph {
+    fn subgraph_id(&'a self, s: &Subgraph) -> Option<crate::Id<'a>> {
+        self.0.subgraph_id(s)
+    }
+} {
+    fn rank_order(&'a self, s: &Subgraph) -> crate::Nodes<'a, Node> {
+        self.0.subgraph_nodes(s)
+    }
+});
+
+#[test]
+fn rank_order() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(3);
+    let g = RankOrderGraph(LabelledGraph::new(
+        "rank_order",
+        labels,
+        vec![],
+        vec![vec![2, 0, 1]],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph rank_order {
+    subgraph cluster_0 {
+        label="";
+        N2;
+        N0;
+        N1;
+        {
+            rank=same;
+            N2 -> N0 -> N1[style=invis];
+        }
+    }
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+}
+"#
+    );
+}
+
+#[test]
+fn label_str_escaping_matches_esc_str() {
+    let input = "say \"hi\"\nà tous";
+
+    assert_eq!(
+        LabelStr(input.into()).to_string(),
+        EscStr(input.into()).to_string()
+    );
+    assert_eq!(
+        LabelStr(input.into()).to_string(),
+        "\"say \\\"hi\\\"\\nà tous\""
+    );
+
+    assert_eq!(LabelStr(r"a\b".into()).to_string(), r#""a\\b""#);
+    assert_eq!(EscStr(r"a\b".into()).to_string(), r#""a\b""#);
+}
+
+labelled_graph!(LabelFontGraph {
+    fn edge_labelfontname(&'a self, _e: &&'a Edge) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("Courier".into()))
+    }
+
+    fn edge_labelfontsize(&'a self, e: &&'a Edge) -> Option<f64> {
+        if e.to == 2 {
+            Some(f64::NAN)
+        } else {
+            Some(8.5)
+        }
+    }
+
+    fn edge_labelfontcolor(&'a self, _e: &&'a Edge) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("red".into()))
+    }
+});
+
+#[test]
+fn edge_label_font() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(3);
+    let g = LabelFontGraph(LabelledGraph::new(
+        "label_font",
+        labels,
+        vec![
+            edge(0, 1, "E", crate::Style::None, None),
+            edge(0, 2, "NaN", crate::Style::None, None),
+        ],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph label_font {
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N0 -> N1[label="E"][labelfontname="Courier"][labelfontsize=8.5][labelfontcolor="red"];
+    N0 -> N2[label="NaN"][labelfontname="Courier"][labelfontcolor="red"];
+}
+"#
+    );
+}
+
+#[test]
+fn kind_is_directed() {
+    assert!(crate::Kind::Digraph.is_directed());
+    assert!(!crate::Kind::Graph.is_directed());
+}
+
+labelled_graph!(PinGraph {
+    fn node_pin(&'a self, n: &Node) -> bool {
+        *n == 0
+    }
+
+    fn node_attributes(&'a self, n: &Node) -> Vec<(String, crate::label::Text<'a>)> {
+        if *n == 0 {
+            vec![("pos".to_string(), LabelStr("1,2".into()))]
+        } else {
+            Vec::new()
+        }
+    }
+});
+
+#[test]
+fn node_pin() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = PinGraph(LabelledGraph::new("pin", labels, vec![], vec![], None));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph pin {
+    N0[label="N0"][pin=true][pos="1,2"];
+    N1[label="N1"];
+}
+"#
+    );
+}
+
+#[test]
+fn write_attr() {
+    let mut writer = Vec::new();
+
+    crate::write_attr(&mut writer, "label", LabelStr("say \"hi\"".into())).unwrap();
+    crate::write_attr_opt(&mut writer, "color", Some(LabelStr("red".into()))).unwrap();
+    crate::write_attr_opt::<_, f64>(&mut writer, "weight", None).unwrap();
+
+    assert_eq!(
+        String::from_utf8(writer).unwrap(),
+        r#"[label="say \"hi\""][color="red"]"#
+    );
+}
+
+#[test]
+fn rank_order_block_is_anonymous() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = RankOrderGraph(LabelledGraph::new(
+        "rank_order",
+        labels,
+        vec![],
+        vec![vec![0, 1]],
+        None,
+    ));
+
+    let output = test_input_opts(&g, &[]).unwrap();
+    let block = output
+        .lines()
+        .skip_while(|line| !line.contains("rank=same"))
+        .collect::<Vec<_>>();
+    let opening = output
+        .lines()
+        .take_while(|line| !line.contains("rank=same"))
+        .last()
+        .unwrap();
+
+    assert_eq!(opening.trim(), "{");
+    assert!(!block.iter().any(|line| line.contains("subgraph")));
+    assert_eq!(output.matches("cluster_").count(), 1);
+}
+
+#[test]
+fn escape_long_label() {
+    let line = "a \"quoted\" \\ line\n";
+    let input = line.repeat(1024 * 1024 / line.len());
+
+    let output = LabelStr(input.as_str().into()).to_string();
+
+    assert_eq!(
+        output,
+        format!(
+            "\"{}\"",
+            r#"a \"quoted\" \\ line\n"#.repeat(1024 * 1024 / line.len())
+        )
+    );
+}
+
+labelled_graph!(UnconstrainedGraph {
+    fn edge_weight(&'a self, e: &&'a Edge) -> Option<f64> {
+        Some(e.to as f64 * 5.)
+    }
+
+    fn edge_constraint(&'a self, e: &&'a Edge) -> bool {
+        e.to != 2
+    }
+});
+
+#[test]
+fn weight_without_constraint() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(3);
+    let g = UnconstrainedGraph(LabelledGraph::new(
+        "constraint",
+        labels,
+        vec![
+            edge(0, 1, "E1", crate::Style::None, None),
+            edge(0, 2, "E2", crate::Style::None, None),
+        ],
+        vec![],
+        None,
+    ));
+
+    let mut writer = Vec::new();
+    let warnings =
+        crate::render_with_warnings(&g, &mut writer, &[crate::render::Option::Validate]).unwrap();
+
+    assert_eq!(
+        String::from_utf8(writer).unwrap(),
+        r#"digraph constraint {
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N0 -> N1[label="E1"][weight=5];
+    N0 -> N2[label="E2"][weight=10][constraint=false];
+}
+"#
+    );
+    assert_eq!(
+        warnings,
+        vec![crate::Warning::WeightWithoutConstraint(
+            "N0 -> N2".to_string()
+        )]
+    );
+
+    let warnings = crate::render_with_warnings(&g, &mut std::io::sink(), &[]).unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn render_owned() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("owned", labels, vec![], vec![], None);
+
+    let writer = crate::render_owned(&g, Vec::new(), &[]).unwrap();
+
+    assert_eq!(
+        String::from_utf8(writer).unwrap(),
+        r#"digraph owned {
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+labelled_graph!(MrecordGraph {
+    fn node_label(&'a self, _n: &Node) -> crate::Result<crate::label::Text<'a>> {
+        Ok(LabelStr("{left|<mid> middle|right}".into()))
+    }
+
+    fn node_shape(&'a self, _n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("Mrecord".into()))
+    }
+});
+
+#[test]
+fn mrecord_shape() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = MrecordGraph(LabelledGraph::new("mrecord", labels, vec![], vec![], None));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph mrecord {
+    N0[label="{left|<mid> middle|right}"][shape="Mrecord"];
+}
+"#
+    );
+}
+
+labelled_graph!(LenGraph {
+    fn edge_len(&'a self, e: &&'a Edge) -> Option<f64> {
+        Some(e.to as f64 * 1.5)
+    }
+});
+
+#[test]
+fn edge_len() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = LenGraph(LabelledGraph::new(
+        "len",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph len {
+    N0[label="N0"];
+    N1[label="N1"];
+    N0 -> N1[label="E"][len=1.5];
+}
+"#
+    );
+}
+
+#[test]
+fn color() {
+    assert_eq!(
+        crate::Color::parse("#1a2B3c"),
+        Some(crate::Color::new(26, 43, 60))
+    );
+    assert_eq!(
+        crate::Color::parse("#ff000080"),
+        Some(crate::Color::new(255, 0, 0))
+    );
+    assert_eq!(
+        crate::Color::parse("Navy"),
+        Some(crate::Color::new(0, 0, 128))
+    );
+    assert_eq!(
+        crate::Color::parse("grey").map(|color| color.to_string()),
+        Some("#bebebe".to_string())
+    );
+    assert_eq!(crate::Color::parse("#fff"), None);
+    assert_eq!(crate::Color::parse("0.5 0.5 0.5"), None);
+
+    assert_eq!(crate::Color::parse("3"), Some(crate::Color::Scheme(3)));
+    assert_eq!(crate::Color::parse("0"), None);
+
+    assert_eq!(crate::Color::new(0, 0, 0).luminance(), Some(0.));
+    assert_eq!(crate::Color::new(255, 255, 255).luminance(), Some(1.));
+    assert_eq!(crate::Color::Scheme(1).luminance(), None);
+    assert_eq!(
+        crate::Color::new(0, 0, 0).lighten(0.5).to_string(),
+        "#808080"
+    );
+}
+
+labelled_graph!(DarkColorsGraph {
+    fn node_color(&'a self, n: &Node) -> Option<crate::label::Text<'a>> {
+        let color = if *n == 0 { "navy" } else { "yellow" };
+
+        Some(LabelStr(color.into()))
+    }
+});
+
+#[test]
+fn dark_theme_lightens_colors() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = DarkColorsGraph(LabelledGraph::new(
+        "dark",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, Some("#000000"))],
+        vec![],
+        None,
+    ));
+
+    let r = test_input_opts(
+        &g,
+        &[
+            crate::render::Option::DarkTheme,
+            crate::render::Option::LightenColors,
+        ],
+    );
+
+    assert_eq!(
+        r.unwrap(),
+        r##"digraph dark {
+    graph[bgcolor="black" fontcolor="white"];
+    node[color="white" fontcolor="white"];
+    edge[color="white" fontcolor="white"];
+    N0[label="N0"][color="#9999cc"];
+    N1[label="N1"][color="yellow"];
+    N0 -> N1[label="E"][color="#999999"];
+}
+"##
+    );
+}
+
+#[test]
+fn stylesheet() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("stylesheet", labels, vec![], vec![], None);
+
+    let r = test_input_opts(
+        &g,
+        &[crate::render::Option::Stylesheet("theme.css".to_string())],
+    );
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph stylesheet {
+    graph[stylesheet="theme.css"];
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+labelled_graph!(UnsortedAttributesGraph {
+    fn graph_attributes(&'a self) -> Vec<(String, crate::label::Text<'a>)> {
+        vec![
+            ("splines".to_string(), LabelStr("ortho".into())),
+            ("rankdir".to_string(), LabelStr("LR".into())),
+        ]
+    }
+
+    fn node_attributes(&'a self, _n: &Node) -> Vec<(String, crate::label::Text<'a>)> {
+        vec![
+            ("tooltip".to_string(), LabelStr("tip".into())),
+            ("fillcolor".to_string(), LabelStr("red".into())),
+        ]
+    }
+});
+
+#[test]
+fn sorted_attributes() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = UnsortedAttributesGraph(LabelledGraph::new("sorted", labels, vec![], vec![], None));
+
+    let r = test_input_opts(&g, &[crate::render::Option::SortAttributes]);
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph sorted {
+    graph[rankdir="LR" splines="ortho"];
+    N0[fillcolor="red"][label="N0"][tooltip="tip"];
+}
+"#
+    );
+}
+
+labelled_graph!(SvgIdGraph {
+    fn graph_svg_id(&'a self) -> Option<String> {
+        Some("first graph".to_string())
+    }
+});
+
+#[test]
+fn graph_svg_id() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = SvgIdGraph(LabelledGraph::new("svg_id", labels, vec![], vec![], None));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph svg_id {
+    graph[id="first graph"];
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+#[test]
+fn adjacency_map() {
+    let mut g = std::collections::BTreeMap::new();
+    g.insert("b.c", vec!["a 1"]);
+    g.insert("a 1", vec!["b.c", "dé"]);
+
+    let expected = r#"digraph G {
+    "a 1"[label="a 1"];
+    "b.c"[label="b.c"];
+    "dé"[label="dé"];
+    "a 1" -> "b.c"[label=""];
+    "a 1" -> "dé"[label=""];
+    "b.c" -> "a 1"[label=""];
+}
+"#;
+
+    assert_eq!(test_input_opts(&g, &[]).unwrap(), expected);
+
+    let g = g.into_iter().collect::<std::collections::HashMap<_, _>>();
+    assert_eq!(test_input_opts(&g, &[]).unwrap(), expected);
+}
+
+#[test]
+fn quoted_id() {
+    assert_eq!(crate::Id::quoted("node 1").to_string(), r#""node 1""#);
+    assert_eq!(crate::Id::quoted("a.b.c").to_string(), r#""a.b.c""#);
+    assert_eq!(crate::Id::quoted("nœud").to_string(), r#""nœud""#);
+    assert_eq!(
+        crate::Id::quoted(r#"say "hi" \o/"#).to_string(),
+        r#""say \"hi\" \\o/""#
+    );
+    assert_eq!(crate::Id::new("node").unwrap().to_string(), "node");
+}
+
+#[test]
+fn numeral_id() {
+    for numeral in ["0", "42", "3.14", "-0.5", ".5", "-.5", "1."] {
+        assert_eq!(crate::Id::new(numeral).unwrap().to_string(), numeral);
+    }
+
+    for invalid in ["", "-", ".", "-.", "1.2.3", "1e5", "--1", "1-", "12abc"] {
+        assert!(crate::Id::new(invalid).is_err(), "{invalid:?}");
+    }
+}
+
+labelled_graph!(RankedGraph {} {
+    fn node_rank(&'a self, n: &Node) -> Option<i32> {
+        Some(*n as i32 / 2)
+    }
+});
+
+#[test]
+fn node_rank() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(6);
+    let g = RankedGraph(LabelledGraph::new(
+        "ranks",
+        labels,
+        vec![edge(0, 2, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph ranks {
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N3[label="N3"];
+    N4[label="N4"];
+    N5[label="N5"];
+    {
+        rank=same;
+        N0;
+        N1;
+    }
+    {
+        rank=same;
+        N2;
+        N3;
+    }
+    {
+        rank=same;
+        N4;
+        N5;
+    }
+    N0 -> N2[label="E"];
+}
+"#
+    );
+}
+
+#[test]
+fn id_with_port() {
+    let id = || crate::Id::new("node0").unwrap();
+
+    assert_eq!(id().with_port("f0", None).to_string(), "node0:f0");
+    assert_eq!(
+        id().with_port("f0", Some(crate::Compass::NW)).to_string(),
+        "node0:f0:nw"
+    );
+    assert_eq!(
+        id().with_port("field 1", Some(crate::Compass::Any))
+            .to_string(),
+        r#"node0:"field 1":_"#
+    );
+    assert_eq!(
+        crate::Id::quoted("a b")
+            .with_port("f0", Some(crate::Compass::C))
+            .into_owned()
+            .to_string(),
+        r#""a b":f0:c"#
+    );
+}
+
+labelled_graph!(PortGraph {
+    fn node_label(&'a self, _n: &Node) -> crate::Result<crate::label::Text<'a>> {
+        Ok(LabelStr("<f0> left|<f1> right".into()))
+    }
+
+    fn node_shape(&'a self, _n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("record".into()))
+    }
+
+    fn edge_source_port(
+        &'a self,
+        _e: &&'a Edge,
+    ) -> Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)> {
+        Some(("f1".into(), Some(crate::Compass::SE)))
+    }
+
+    fn edge_target_port(
+        &'a self,
+        _e: &&'a Edge,
+    ) -> Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)> {
+        Some(("f0".into(), None))
+    }
+});
+
+#[test]
+fn edge_ports() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = PortGraph(LabelledGraph::new(
+        "ports",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph ports {
+    N0[label="<f0> left|<f1> right"][shape="record"];
+    N1[label="<f0> left|<f1> right"][shape="record"];
+    N0:f1:se -> N1:f0[label="E"];
+}
+"#
+    );
+}
+
+labelled_graph!(FontsizeGraph {
+    fn graph_fontsize(&'a self) -> Option<f64> {
+        Some(20.)
+    }
+
+    fn subgraph_id(&'a self, s: &Subgraph) -> Option<crate::Id<'a>> {
+        self.0.subgraph_id(s)
+    }
+
+    fn subgraph_fontsize(&'a self, _s: &Subgraph) -> Option<f64> {
+        Some(9.5)
+    }
+});
+
+#[test]
+fn fontsize() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = FontsizeGraph(LabelledGraph::new(
+        "fontsize",
+        labels,
+        vec![],
+        vec![vec![0]],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph fontsize {
+    subgraph cluster_0 {
+        label="";
+        fontsize=9.5;
+        N0;
+    }
+    graph[fontsize=20];
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+labelled_graph!(NonFiniteFontsizeGraph {
+    fn graph_fontsize(&'a self) -> Option<f64> {
+        Some(f64::NAN)
+    }
+
+    fn subgraph_id(&'a self, s: &Subgraph) -> Option<crate::Id<'a>> {
+        self.0.subgraph_id(s)
+    }
+
+    fn subgraph_fontsize(&'a self, _s: &Subgraph) -> Option<f64> {
+        Some(f64::INFINITY)
+    }
+});
+
+#[test]
+fn non_finite_fontsize() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = NonFiniteFontsizeGraph(LabelledGraph::new(
+        "fontsize",
+        labels,
+        vec![],
+        vec![vec![0]],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph fontsize {
+    subgraph cluster_0 {
+        label="";
+        N0;
+    }
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+#[test]
+fn indent_writer() {
+    let mut output = Vec::new();
+    let mut w = crate::indent_writer::IndentWriter::new(&mut output);
+
+    w.write_fmt(format_args!("a {{\n")).unwrap();
+    w.indent();
+    w.write_fmt(format_args!("b")).unwrap();
+    w.write_fmt(format_args!(";\n\nc {{\n")).unwrap();
+    w.indent();
+    w.write_fmt(format_args!("d;\n")).unwrap();
+    w.dedent();
+    w.write_fmt(format_args!("}}\n")).unwrap();
+    w.dedent();
+    w.dedent();
+    w.write_fmt(format_args!("}}\n")).unwrap();
+
+    {
+        let mut nested = crate::indent_writer::IndentWriter::new(&mut w);
+        nested.indent();
+        nested.write_fmt(format_args!("e;\nf;\n")).unwrap();
+    }
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "a {
+    b;
+
+    c {
+        d;
+    }
+}
+    e;
+    f;
+"
+    );
+}
+
+#[test]
+fn id_as_str() {
+    let id = crate::Id::new("node").unwrap();
+    let derived = crate::Id::new(format!("{}_copy", id.as_str())).unwrap();
+
+    assert_eq!(derived.as_str(), "node_copy");
+    assert_eq!(crate::Id::quoted(r#"a "b""#).as_str(), r#"a "b""#);
+    assert_eq!(
+        crate::Id::new("n0").unwrap().with_port("f0", None).as_str(),
+        "n0"
+    );
+}
+
+labelled_graph!(NodeStylesGraph {
+    fn node_styles(&'a self, n: &Node) -> crate::StyleSet {
+        match n {
+            0 => [crate::Style::Filled, crate::Style::Dashed]
+                .into_iter()
+                .collect(),
+            _ => [crate::Style::None].into_iter().collect(),
+        }
+    }
+});
+
+#[test]
+fn node_styles() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = NodeStylesGraph(LabelledGraph::new(
+        "node_styles",
+        labels,
+        vec![],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph node_styles {
+    N0[label="N0"][style="filled,dashed"];
+    N1[label="N1"];
+}
+"#
+    );
+}
+
+labelled_graph!(CommentGraph {
+    fn graph_comment(&'a self) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("generated".into()))
+    }
+
+    fn node_comment(&'a self, n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr(format!("node {n}").into()))
+    }
+
+    fn edge_comment(&'a self, _e: &&'a Edge) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("edge".into()))
+    }
+});
+
+#[test]
+fn comments() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = CommentGraph(LabelledGraph::new(
+        "comments",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph comments {
+    graph[comment="generated"];
+    N0[label="N0"][comment="node 0"];
+    N1[label="N1"][comment="node 1"];
+    N0 -> N1[label="E"][comment="edge"];
+}
+"#
+    );
+}
+
+labelled_graph!(SkewedGraph {
+    fn node_shape(&'a self, n: &Node) -> Option<crate::label::Text<'a>> {
+        let shape = if *n == 0 { "polygon" } else { "box" };
+
+        Some(LabelStr(shape.into()))
+    }
+
+    fn node_skew(&'a self, _n: &Node) -> Option<f64> {
+        Some(0.5)
+    }
+
+    fn node_distortion(&'a self, n: &Node) -> Option<f64> {
+        (*n == 0).then_some(-0.25)
+    }
+});
+
+#[test]
+fn skew_and_distortion() {
+    let g = |nodes| {
+        let labels: Trivial = NodeLabels::UnlabelledNodes(nodes);
+        SkewedGraph(LabelledGraph::new("skew", labels, vec![], vec![], None))
+    };
+
+    assert_eq!(
+        test_input_opts(&g(2), &[]).unwrap(),
+        r#"digraph skew {
+    N0[label="N0"][shape="polygon"][skew=0.5][distortion=-0.25];
+    N1[label="N1"][shape="box"][skew=0.5];
+}
+"#
+    );
+
+    assert!(crate::validate(&g(1), &[]).is_ok());
+    assert!(matches!(
+        crate::validate(&g(2), &[]),
+        Err(crate::Error::IncompatiblePolygonShape(shape)) if shape == "box"
+    ));
+}
+
+labelled_graph!(PenwidthGraph {
+    fn node_penwidth(&'a self, n: &Node) -> Option<f64> {
+        Some([2.5, f64::NAN][*n])
+    }
+
+    fn edge_penwidth(&'a self, e: &&'a Edge) -> Option<f64> {
+        Some([3., f64::INFINITY][e.from])
+    }
+});
+
+#[test]
+fn penwidth() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = PenwidthGraph(LabelledGraph::new(
+        "penwidth",
+        labels,
+        vec![
+            edge(0, 1, "E1", crate::Style::None, None),
+            edge(1, 0, "E2", crate::Style::None, None),
+        ],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph penwidth {
+    N0[label="N0"][penwidth=2.5];
+    N1[label="N1"];
+    N0 -> N1[label="E1"][penwidth=3];
+    N1 -> N0[label="E2"];
+}
+"#
+    );
+}
+
+struct SplitLabeller;
+
+impl<'a> crate::Labeller<'a> for SplitLabeller {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = ();
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        crate::Id::new("split")
+    }
+
+    fn node_id(&'a self, n: &Node) -> crate::Result<crate::Id<'a>> {
+        id_name(n)
+    }
+
+    fn edge_label(&'a self, e: &(Node, Node)) -> crate::label::Text<'a> {
+        LabelStr(format!("{}-{}", e.0, e.1).into())
+    }
+}
+
+struct SplitWalk(Vec<(Node, Node)>);
+
+impl<'a> crate::GraphWalk<'a> for SplitWalk {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = ();
+
+    fn nodes(&'a self) -> crate::Nodes<'a, Node> {
+        vec![0, 1, 2].into()
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, (Node, Node)> {
+        self.0.as_slice().into()
+    }
+
+    fn source(&'a self, edge: &(Node, Node)) -> Node {
+        edge.0
+    }
+
+    fn target(&'a self, edge: &(Node, Node)) -> Node {
+        edge.1
+    }
+}
+
+#[test]
+fn render_split() {
+    let walk = SplitWalk(vec![(0, 1), (1, 2)]);
+    let mut writer = Vec::new();
+
+    crate::render_split(&SplitLabeller, &walk, &mut writer, &[]).unwrap();
+
+    assert_eq!(
+        String::from_utf8(writer).unwrap(),
+        r#"digraph split {
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N0 -> N1[label="0-1"];
+    N1 -> N2[label="1-2"];
+}
+"#
+    );
+}
+
+labelled_graph!(FontGraph {
+    fn node_fontsize(&'a self, n: &Node) -> Option<f64> {
+        (*n == 0).then_some(14.)
+    }
+
+    fn node_fontcolor(&'a self, n: &Node) -> Option<crate::label::Text<'a>> {
+        (*n == 0).then(|| LabelStr("blue".into()))
+    }
+
+    fn edge_fontsize(&'a self, _e: &&'a Edge) -> Option<f64> {
+        Some(8.5)
+    }
+
+    fn edge_fontcolor(&'a self, _e: &&'a Edge) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("gray".into()))
+    }
+});
+
+#[test]
+fn element_fonts() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = FontGraph(LabelledGraph::new(
+        "fonts",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph fonts {
+    N0[label="N0"][fontsize=14][fontcolor="blue"];
+    N1[label="N1"];
+    N0 -> N1[label="E"][fontsize=8.5][fontcolor="gray"];
+}
+"#
+    );
+}
+
+labelled_graph!(EndpointLabelsGraph {
+    fn edge_headlabel(&'a self, e: &&'a Edge) -> Option<crate::label::Text<'a>> {
+        if e.to == 2 {
+            Some(EscStr(r#"1..*\lmany "\E"\n\N"#.into()))
+        } else {
+            Some(LabelStr("1..*".into()))
+        }
+    }
+
+    fn edge_taillabel(&'a self, _e: &&'a Edge) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("0 & 1".into()))
+    }
+
+    fn edge_headlabel_color(&'a self, _e: &&'a Edge) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("red".into()))
+    }
+
+    fn edge_taillabel_color(&'a self, e: &&'a Edge) -> Option<crate::label::Text<'a>> {
+        (e.to == 2).then(|| LabelStr("blue".into()))
+    }
+});
+
+#[test]
+fn endpoint_label_colors() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(3);
+    let g = EndpointLabelsGraph(LabelledGraph::new(
+        "endpoints",
+        labels,
+        vec![
+            edge(0, 1, "E1", crate::Style::None, None),
+            edge(0, 2, "E2", crate::Style::None, None),
+        ],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph endpoints {
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N0 -> N1[label="E1"][headlabel=<<font color="red">1..*</font>>][taillabel="0 & 1"];
+    N0 -> N2[label="E2"][headlabel=<<font color="red">1..*<br align="left"/>many &quot;\E&quot;<br/>\N</font>>][taillabel=<<font color="blue">0 &amp; 1</font>>];
+}
+"#
+    );
+}
+
+#[test]
+fn overlap() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("overlap", labels, vec![], vec![], None);
+
+    let r = test_input_opts(
+        &g,
+        &[
+            crate::render::Option::Overlap(crate::Overlap::Prism(Some(1000))),
+            crate::render::Option::OverlapScaling(crate::Float(-4.)),
+        ],
+    );
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph overlap {
+    graph[overlap="prism1000" overlap_scaling=-4];
+    N0[label="N0"];
+}
+"#
+    );
+
+    assert_eq!(crate::Overlap::Prism(None).to_string(), "prism");
+    assert_eq!(crate::Overlap::Vpsc.to_string(), "vpsc");
+}
+
+labelled_graph!(UndirectedGraph {
+    fn kind(&self) -> crate::Kind {
+        crate::Kind::Graph
+    }
+
+    fn edge_arrowsize(&'a self, _e: &&'a Edge) -> Option<f64> {
+        Some(2.)
+    }
+});
+
+#[test]
+fn undirected_triangle() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(3);
+    let g = UndirectedGraph(LabelledGraph::new(
+        "triangle",
+        labels,
+        vec![
+            edge(0, 1, "A", crate::Style::None, None),
+            edge_with_arrows(
+                1,
+                2,
+                "B",
+                crate::Style::None,
+                crate::Arrow::normal(),
+                crate::Arrow::normal(),
+                None,
+            ),
+            edge(2, 0, "C", crate::Style::None, None),
+        ],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"graph triangle {
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N0 -- N1[label="A"];
+    N1 -- N2[label="B"];
+    N2 -- N0[label="C"];
+}
+"#
+    );
+}
+
+labelled_graph!(StrictGraph {
+    fn strict(&self) -> bool {
+        true
+    }
+});
+
+#[test]
+fn strict() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = StrictGraph(LabelledGraph::new(
+        "G",
+        labels,
+        vec![
+            edge(0, 1, "A", crate::Style::None, None),
+            edge(0, 1, "B", crate::Style::None, None),
+        ],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"strict digraph G {
+    N0[label="N0"];
+    N1[label="N1"];
+    N0 -> N1[label="A"];
+    N0 -> N1[label="B"];
+}
+"#
+    );
+}
+
+/// Fails with `BrokenPipe` once `capacity` bytes are written.
+struct FailingWriter {
+    capacity: usize,
+    written: Vec<u8>,
+}
+
+impl std::io::Write for FailingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(self.capacity - self.written.len());
+
+        if len == 0 {
+            return Err(std::io::ErrorKind::BrokenPipe.into());
+        }
+
+        self.written.extend_from_slice(&buf[..len]);
+
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn writer_error() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = LabelledGraph::new(
+        "broken",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    );
+
+    for capacity in [0, 10, 30] {
+        let mut writer = FailingWriter {
+            capacity,
+            written: Vec::new(),
+        };
+
+        match crate::render(&g, &mut writer) {
+            Err(crate::Error::Io(err)) => assert_eq!(err.kind(), std::io::ErrorKind::BrokenPipe),
+            r => panic!("unexpected result: {r:?}"),
+        }
+        assert_eq!(writer.written.len(), capacity);
+    }
+}
+
+labelled_graph!(TitledGraph {
+    fn graph_label(&'a self) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("My \"graph\"".into()))
+    }
+
+    fn graph_labelloc(&'a self) -> Option<char> {
+        Some('t')
+    }
+});
+
+#[test]
+fn graph_label() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = TitledGraph(LabelledGraph::new(
+        "titled",
+        labels,
+        vec![],
+        vec![vec![0]],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph titled {
+    label="My \"graph\"";
+    labelloc="t";
+    subgraph {
+        label="";
+        N0;
+    }
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+labelled_graph!(ColorschemeGraph {
+    fn graph_colorscheme(&'a self) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("set19".into()))
+    }
+
+    fn node_colorscheme(&'a self, n: &Node) -> Option<crate::label::Text<'a>> {
+        (*n == 1).then(|| LabelStr("accent3".into()))
+    }
+
+    fn node_color(&'a self, n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(crate::Color::Scheme(*n as u8 + 1).into())
+    }
+});
+
+#[test]
+fn colorscheme() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = ColorschemeGraph(LabelledGraph::new(
+        "colorscheme",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, Some("3"))],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph colorscheme {
+    graph[colorscheme="set19"];
+    node[colorscheme="set19"];
+    edge[colorscheme="set19"];
+    N0[label="N0"][color="1"];
+    N1[label="N1"][colorscheme="accent3"][color="2"];
+    N0 -> N1[label="E"][color="3"];
+}
+"#
+    );
+}
+
+struct NestedGraph;
+
+impl<'a> crate::Labeller<'a> for NestedGraph {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = usize;
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        crate::Id::new("nested")
+    }
+
+    fn node_id(&'a self, n: &Node) -> crate::Result<crate::Id<'a>> {
+        id_name(n)
+    }
+
+    fn subgraph_id(&'a self, s: &usize) -> Option<crate::Id<'a>> {
+        crate::Id::new(format!("cluster_{s}")).ok()
+    }
+}
+
+impl<'a> crate::GraphWalk<'a> for NestedGraph {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = usize;
+
+    fn nodes(&'a self) -> crate::Nodes<'a, Node> {
+        (0..4).collect()
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, (Node, Node)> {
+        vec![].into()
+    }
+
+    fn source(&'a self, edge: &(Node, Node)) -> Node {
+        edge.0
+    }
+
+    fn target(&'a self, edge: &(Node, Node)) -> Node {
+        edge.1
+    }
+
+    fn subgraphs(&'a self) -> crate::Subgraphs<'a, usize> {
+        vec![0].into()
+    }
+
+    fn subgraph_nodes(&'a self, s: &usize) -> crate::Nodes<'a, Node> {
+        match s {
+            0 => vec![0, 1, 2, 3].into(),
+            1 => vec![1, 3].into(),
+            2 => vec![2].into(),
+            _ => vec![3].into(),
+        }
+    }
+
+    fn subgraph_children(&'a self, s: &usize) -> crate::Subgraphs<'a, usize> {
+        match s {
+            0 => vec![1, 2].into(),
+            1 => vec![3].into(),
+            _ => vec![].into(),
+        }
+    }
+}
+
+#[test]
+fn nested_subgraphs() {
+    let mut counter = Counter::default();
+    crate::walk(&NestedGraph, &mut counter);
+    assert_eq!(counter.subgraphs, 4);
+
+    assert_eq!(
+        test_input_opts(&NestedGraph, &[]).unwrap(),
+        r#"digraph nested {
+    subgraph cluster_0 {
+        label="";
+        N0;
+        subgraph cluster_1 {
+            label="";
+            N1;
+            subgraph cluster_3 {
+                label="";
+                N3;
+            }
+        }
+
+        subgraph cluster_2 {
+            label="";
+            N2;
+        }
+    }
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N3[label="N3"];
+}
+"#
+    );
+}
+
+#[test]
+fn induced_subgraph() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(5);
+    let g = LabelledGraph::new(
+        "induced",
+        labels,
+        vec![
+            edge(0, 1, "A", crate::Style::None, None),
+            edge(1, 2, "B", crate::Style::None, None),
+            edge(2, 3, "C", crate::Style::None, None),
+            edge(3, 4, "D", crate::Style::None, None),
+            edge(4, 0, "E", crate::Style::None, None),
+        ],
+        vec![vec![0, 3]],
+        None,
+    );
+    let view = crate::InducedSubgraph::new(&g, |n: &Node| *n != 1 && *n != 3);
+
+    assert_eq!(
+        test_input_opts(&view, &[]).unwrap(),
+        r#"digraph induced {
+    subgraph cluster_0 {
+        label="";
+        N0;
+    }
+    N0[label="N0"];
+    N2[label="N2"];
+    N4[label="N4"];
+    N4 -> N0[label="E"];
+}
+"#
+    );
+}
+
+#[test]
+fn induced_subgraph_drops_empty_subgraphs() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(4);
+    let g = LabelledGraph::new(
+        "induced",
+        labels,
+        vec![],
+        vec![vec![0, 2], vec![1, 3]],
+        None,
+    );
+    let view = crate::InducedSubgraph::new(&g, |n: &Node| *n != 1 && *n != 3);
+
+    assert_eq!(
+        test_input_opts(&view, &[]).unwrap(),
+        r#"digraph induced {
+    subgraph cluster_0 {
+        label="";
+        N0;
+        N2;
+    }
+    N0[label="N0"];
+    N2[label="N2"];
+}
+"#
+    );
+}
+
+/// Overrides every default method, so that rendering it through
+/// `InducedSubgraph` or `render_split` shows those not forwarded.
+struct Overridden(crate::Kind);
+
+impl<'a> crate::Labeller<'a> for Overridden {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = usize;
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        crate::Id::new("overridden")
+    }
+
+    fn graph_label(&'a self) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("graph".into()))
+    }
+
+    fn graph_labelloc(&'a self) -> Option<char> {
+        Some('t')
+    }
+
+    fn graph_colorscheme(&'a self) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("x11".into()))
+    }
+
+    fn graph_attributes(&'a self) -> Vec<(String, crate::label::Text<'a>)> {
+        vec![("nodesep".to_string(), LabelStr("1".into()))]
+    }
+
+    fn graph_svg_id(&'a self) -> Option<String> {
+        Some("svg".to_string())
+    }
+
+    fn graph_fontsize(&'a self) -> Option<f64> {
+        Some(10.)
+    }
+
+    fn graph_comment(&'a self) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("graph comment".into()))
+    }
+
+    fn node_defaults(&'a self) -> Vec<(String, crate::label::Text<'a>)> {
+        vec![("margin".to_string(), LabelStr("0".into()))]
+    }
+
+    fn edge_defaults(&'a self) -> Vec<(String, crate::label::Text<'a>)> {
+        vec![("minlen".to_string(), LabelStr("2".into()))]
+    }
+
+    fn node_id(&'a self, n: &Node) -> crate::Result<crate::Id<'a>> {
+        id_name(n)
+    }
+
+    fn node_shape(&'a self, _: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("polygon".into()))
+    }
+
+    fn node_label(&'a self, n: &Node) -> crate::Result<crate::label::Text<'a>> {
+        Ok(LabelStr(format!("node {n}").into()))
+    }
+
+    fn node_xlabel(&'a self, _: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("xlabel".into()))
+    }
+
+    fn node_style(&'a self, _: &Node) -> crate::Style {
+        crate::Style::Bold
+    }
+
+    fn node_colorscheme(&'a self, _: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("blues9".into()))
+    }
+
+    fn node_color(&'a self, _: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("red".into()))
+    }
+
+    fn node_fillcolor(&'a self, _: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("blue".into()))
+    }
+
+    fn node_url(&'a self, _: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("https://example.org".into()))
+    }
+
+    fn node_target(&'a self, _: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("_blank".into()))
+    }
+
+    fn node_comment(&'a self, _: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("node comment".into()))
+    }
+
+    fn node_skew(&'a self, _: &Node) -> Option<f64> {
+        Some(0.5)
+    }
+
+    fn node_distortion(&'a self, _: &Node) -> Option<f64> {
+        Some(0.25)
+    }
+
+    fn node_penwidth(&'a self, _: &Node) -> Option<f64> {
+        Some(2.)
+    }
+
+    fn node_fontsize(&'a self, _: &Node) -> Option<f64> {
+        Some(12.)
+    }
+
+    fn node_fontcolor(&'a self, _: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("green".into()))
+    }
+
+    fn node_pin(&'a self, _: &Node) -> bool {
+        true
+    }
+
+    fn node_attributes(&'a self, _: &Node) -> Vec<(String, crate::label::Text<'a>)> {
+        vec![("peripheries".to_string(), LabelStr("2".into()))]
+    }
+
+    fn edge_end_arrow(&'a self, _: &(Node, Node)) -> crate::Arrow {
+        crate::Arrow::from_arrow(crate::arrow::Shape::tee())
+    }
+
+    fn edge_start_arrow(&'a self, _: &(Node, Node)) -> crate::Arrow {
+        crate::Arrow::from_arrow(crate::arrow::Shape::dot())
+    }
+
+    fn edge_dir(&'a self, _: &(Node, Node)) -> Option<crate::Direction> {
+        Some(crate::Direction::Both)
+    }
+
+    fn edge_arrowsize(&'a self, _: &(Node, Node)) -> Option<f64> {
+        Some(1.5)
+    }
+
+    fn edge_label(&'a self, e: &(Node, Node)) -> crate::label::Text<'a> {
+        LabelStr(format!("{}-{}", e.0, e.1).into())
+    }
+
+    fn edge_xlabel(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("xlabel".into()))
+    }
+
+    fn edge_headlabel(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("head".into()))
+    }
+
+    fn edge_taillabel(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("tail".into()))
+    }
+
+    fn edge_headlabel_color(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("red".into()))
+    }
+
+    fn edge_taillabel_color(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("blue".into()))
+    }
+
+    fn edge_labelfontname(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("Courier".into()))
+    }
+
+    fn edge_labelfontsize(&'a self, _: &(Node, Node)) -> Option<f64> {
+        Some(8.)
+    }
+
+    fn edge_labelfontcolor(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("gray".into()))
+    }
+
+    fn edge_style(&'a self, _: &(Node, Node)) -> crate::Style {
+        crate::Style::Dashed
+    }
+
+    fn edge_color(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("purple".into()))
+    }
+
+    fn edge_url(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("https://example.org".into()))
+    }
+
+    fn edge_target(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("_top".into()))
+    }
+
+    fn edge_comment(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("edge comment".into()))
+    }
+
+    fn edge_tooltip(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("tooltip".into()))
+    }
+
+    fn edge_labeltooltip(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("label tooltip".into()))
+    }
+
+    fn edge_headtooltip(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("head tooltip".into()))
+    }
+
+    fn edge_tailtooltip(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("tail tooltip".into()))
+    }
+
+    fn edge_attributes(&'a self, _: &(Node, Node)) -> Vec<(String, crate::label::Text<'a>)> {
+        vec![("samehead".to_string(), LabelStr("h".into()))]
+    }
+
+    fn edge_weight(&'a self, _: &(Node, Node)) -> Option<f64> {
+        Some(2.5)
+    }
+
+    fn edge_weight_int(&'a self, _: &(Node, Node)) -> Option<i64> {
+        Some(3)
+    }
+
+    fn edge_constraint(&'a self, _: &(Node, Node)) -> bool {
+        false
+    }
+
+    fn edge_len(&'a self, _: &(Node, Node)) -> Option<f64> {
+        Some(1.25)
+    }
+
+    fn edge_fontsize(&'a self, _: &(Node, Node)) -> Option<f64> {
+        Some(9.)
+    }
+
+    fn edge_fontcolor(&'a self, _: &(Node, Node)) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("orange".into()))
+    }
+
+    fn edge_penwidth(&'a self, _: &(Node, Node)) -> Option<f64> {
+        Some(3.)
+    }
+
+    fn edge_source_port(
+        &'a self,
+        _: &(Node, Node),
+    ) -> Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)> {
+        Some(("out".into(), Some(crate::Compass::S)))
+    }
+
+    fn edge_target_port(
+        &'a self,
+        _: &(Node, Node),
+    ) -> Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)> {
+        Some(("in".into(), Some(crate::Compass::N)))
+    }
+
+    fn subgraph_id(&'a self, s: &usize) -> Option<crate::Id<'a>> {
+        crate::Id::new(format!("cluster_{s}")).ok()
+    }
+
+    fn subgraph_label(&'a self, s: &usize) -> crate::label::Text<'a> {
+        LabelStr(format!("subgraph {s}").into())
+    }
+
+    fn subgraph_style(&'a self, _: &usize) -> crate::Style {
+        crate::Style::Filled
+    }
+
+    fn subgraph_shape(&'a self, _: &usize) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("box".into()))
+    }
+
+    fn subgraph_color(&'a self, _: &usize) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("yellow".into()))
+    }
+
+    fn subgraph_fontsize(&'a self, _: &usize) -> Option<f64> {
+        Some(14.)
+    }
+
+    fn kind(&self) -> crate::Kind {
+        self.0
+    }
+
+    fn strict(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> crate::GraphWalk<'a> for Overridden {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = usize;
+
+    fn nodes(&'a self) -> crate::Nodes<'a, Node> {
+        (0..4).collect()
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, (Node, Node)> {
+        vec![(0, 1), (1, 2)].into()
+    }
+
+    fn source(&'a self, edge: &(Node, Node)) -> Node {
+        edge.0
+    }
+
+    fn target(&'a self, edge: &(Node, Node)) -> Node {
+        edge.1
+    }
+
+    fn source_endpoint(
+        &'a self,
+        edge: &(Node, Node),
+    ) -> crate::Result<crate::EdgeEndpoint<Node, usize>> {
+        Ok(match edge {
+            (1, 2) => crate::EdgeEndpoint::Subgraph(1),
+            _ => crate::EdgeEndpoint::Node(edge.0),
+        })
+    }
+
+    fn target_endpoint(
+        &'a self,
+        edge: &(Node, Node),
+    ) -> crate::Result<crate::EdgeEndpoint<Node, usize>> {
+        Ok(match edge {
+            (0, 1) => crate::EdgeEndpoint::Subgraph(1),
+            _ => crate::EdgeEndpoint::Node(edge.1),
+        })
+    }
+
+    fn subgraphs(&'a self) -> crate::Subgraphs<'a, usize> {
+        vec![0].into()
+    }
+
+    fn subgraph_nodes(&'a self, s: &usize) -> crate::Nodes<'a, Node> {
+        match s {
+            0 => vec![0, 1].into(),
+            _ => vec![2, 3].into(),
+        }
+    }
+
+    fn subgraph_edges(&'a self, s: &usize) -> crate::Edges<'a, (Node, Node)> {
+        match s {
+            0 => vec![(1, 0)].into(),
+            _ => vec![(3, 2)].into(),
+        }
+    }
+
+    fn subgraph_children(&'a self, s: &usize) -> crate::Subgraphs<'a, usize> {
+        match s {
+            0 => vec![1].into(),
+            _ => vec![].into(),
+        }
+    }
+
+    fn subgraph_rank(&'a self, s: &usize) -> Option<crate::Rank> {
+        (*s == 1).then_some(crate::Rank::Same)
+    }
+
+    fn rank_order(&'a self, s: &usize) -> crate::Nodes<'a, Node> {
+        self.subgraph_nodes(s)
+    }
+
+    fn node_rank(&'a self, n: &Node) -> Option<i32> {
+        (*n == 3).then_some(1)
+    }
+}
+
+#[test]
+fn forward_overridden() {
+    for g in [
+        Overridden(crate::Kind::Digraph),
+        Overridden(crate::Kind::Graph),
+    ] {
+        let direct = test_input_opts(&g, &[]).unwrap();
+
+        let view = crate::InducedSubgraph::new(&g, |_: &Node| true);
+        assert_eq!(test_input_opts(&view, &[]).unwrap(), direct);
+
+        let mut writer = Vec::new();
+        crate::render_split(&g, &g, &mut writer, &[]).unwrap();
+        assert_eq!(String::from_utf8(writer).unwrap(), direct);
+    }
+}
+
+struct ClusterEdges;
+
+impl<'a> crate::Labeller<'a> for ClusterEdges {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = usize;
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        crate::Id::new("cluster_edges")
+    }
+
+    fn node_id(&'a self, n: &Node) -> crate::Result<crate::Id<'a>> {
+        id_name(n)
+    }
+
+    fn subgraph_id(&'a self, s: &usize) -> Option<crate::Id<'a>> {
+        crate::Id::new(format!("cluster_{s}")).ok()
+    }
+
+    fn edge_style(&'a self, e: &(Node, Node)) -> crate::Style {
+        if *e == (1, 0) {
+            crate::Style::Filled
+        } else {
+            crate::Style::None
+        }
+    }
+
+    fn edge_weight(&'a self, e: &(Node, Node)) -> Option<f64> {
+        (*e == (0, 0)).then_some(2.)
+    }
+}
+
+impl<'a> crate::GraphWalk<'a> for ClusterEdges {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = usize;
+
+    fn nodes(&'a self) -> crate::Nodes<'a, Node> {
+        (0..3).collect()
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, (Node, Node)> {
+        // Without the edge of the subgraph.
+        vec![(1, 2), (0, 1)].into()
+    }
+
+    fn source(&'a self, edge: &(Node, Node)) -> Node {
+        edge.0
+    }
+
+    fn target(&'a self, edge: &(Node, Node)) -> Node {
+        edge.1
+    }
+
+    fn subgraphs(&'a self) -> crate::Subgraphs<'a, usize> {
+        vec![0].into()
+    }
+
+    fn subgraph_nodes(&'a self, _s: &usize) -> crate::Nodes<'a, Node> {
+        vec![0, 1].into()
+    }
+
+    fn subgraph_edges(&'a self, _s: &usize) -> crate::Edges<'a, (Node, Node)> {
+        vec![(1, 0), (0, 0)].into()
+    }
+}
+
+#[test]
+fn subgraph_edges() {
+    assert_eq!(
+        test_input_opts(&ClusterEdges, &[]).unwrap(),
+        r#"digraph cluster_edges {
+    subgraph cluster_0 {
+        label="";
+        N0;
+        N1;
+        N1 -> N0[label=""][style="filled"];
+        N0 -> N0[label=""][weight=2];
+    }
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N1 -> N2[label=""];
+    N0 -> N1[label=""];
+}
+"#
+    );
+
+    assert_eq!(
+        test_input_opts(&ClusterEdges, &[crate::render::Option::SortEdgesByWeight]).unwrap(),
+        r#"digraph cluster_edges {
+    subgraph cluster_0 {
+        label="";
+        N0;
+        N1;
+        N0 -> N0[label=""][weight=2];
+        N1 -> N0[label=""][style="filled"];
+    }
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N1 -> N2[label=""];
+    N0 -> N1[label=""];
+}
+"#
+    );
+}
+
+#[test]
+fn subgraph_edges_walked() {
+    let warnings = crate::render_with_warnings(&ClusterEdges, &mut Vec::new(), &[]).unwrap();
+    assert_eq!(
+        warnings,
+        vec![crate::Warning::NodeStyleOnEdge(crate::Style::Filled)]
+    );
+
+    let mut counter = Counter::default();
+    crate::walk(&ClusterEdges, &mut counter);
+    assert_eq!(counter.edges, 4);
+}
+
+labelled_graph!(TooltipGraph {
+    fn edge_labeltooltip(&'a self, _e: &&'a Edge) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("click".into()))
+    }
+});
+
+#[test]
+fn edge_labeltooltip() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = TooltipGraph(LabelledGraph::new(
+        "tooltips",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph tooltips {
+    N0[label="N0"];
+    N1[label="N1"];
+    N0 -> N1[label="E"][labeltooltip="click"];
+}
+"#
+    );
+}
+
+struct RankGraph;
+
+impl<'a> crate::Labeller<'a> for RankGraph {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = usize;
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        crate::Id::new("ranks")
+    }
+
+    fn node_id(&'a self, n: &Node) -> crate::Result<crate::Id<'a>> {
+        id_name(n)
+    }
+
+    fn subgraph_id(&'a self, s: &usize) -> Option<crate::Id<'a>> {
+        if *s == 0 {
+            crate::Id::new("cluster_0").ok()
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> crate::GraphWalk<'a> for RankGraph {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = usize;
+
+    fn nodes(&'a self) -> crate::Nodes<'a, Node> {
+        (0..4).collect()
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, (Node, Node)> {
+        vec![].into()
+    }
+
+    fn source(&'a self, edge: &(Node, Node)) -> Node {
+        edge.0
+    }
+
+    fn target(&'a self, edge: &(Node, Node)) -> Node {
+        edge.1
+    }
+
+    fn subgraphs(&'a self) -> crate::Subgraphs<'a, usize> {
+        vec![0, 1].into()
+    }
+
+    fn subgraph_nodes(&'a self, s: &usize) -> crate::Nodes<'a, Node> {
+        if *s == 0 {
+            vec![0, 1].into()
+        } else {
+            vec![2, 3].into()
+        }
+    }
+
+    fn subgraph_rank(&'a self, s: &usize) -> Option<crate::Rank> {
+        if *s == 0 {
+            Some(crate::Rank::Min)
+        } else {
+            Some(crate::Rank::Same)
+        }
+    }
+}
+
+#[test]
+fn subgraph_rank() {
+    assert_eq!(
+        test_input_opts(&RankGraph, &[]).unwrap(),
+        r#"digraph ranks {
+    subgraph cluster_0 {
+        label="";
+        rank=min;
+        N0;
+        N1;
+    }
+
+    {
+        rank=same;
+        N2;
+        N3;
+    }
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N3[label="N3"];
+}
+"#
+    );
+}
+
+#[test]
+#[cfg(feature = "mermaid")]
+fn single_edge_mermaid() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = LabelledGraph::new(
+        "single_edge",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    );
+
+    let mut writer = Vec::new();
+    crate::render_mermaid(&g, &mut writer).unwrap();
+
+    assert_eq!(
+        String::from_utf8(writer).unwrap(),
+        r#"flowchart TD
+    n0["N0"]
+    n1["N1"]
+    n0 -->|"E"| n1
+"#
+    );
+}
+
+#[test]
+#[cfg(feature = "mermaid")]
+fn subgraph_edges_mermaid() {
+    let mut writer = Vec::new();
+    crate::render_mermaid(&ClusterEdges, &mut writer).unwrap();
+
+    assert_eq!(
+        String::from_utf8(writer).unwrap(),
+        r#"flowchart TD
+    n0["N0"]
+    n1["N1"]
+    n2["N2"]
+    n1 --> n2
+    n0 --> n1
+    n1 --> n0
+    n0 --> n0
+"#
+    );
+}
+
+#[cfg(feature = "mermaid")]
+struct MermaidIds;
+
+#[cfg(feature = "mermaid")]
+impl<'a> crate::Labeller<'a> for MermaidIds {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = ();
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        crate::Id::new("mermaid_ids")
+    }
+
+    fn node_id(&'a self, n: &Node) -> crate::Result<crate::Id<'a>> {
+        match n {
+            0 => crate::Id::new("end"),
+            _ => Ok(crate::Id::quoted("a node")),
+        }
+    }
+
+    fn node_label(&'a self, n: &Node) -> crate::Result<crate::label::Text<'a>> {
+        Ok(LabelStr(format!("N{n}").into()))
+    }
+}
+
+#[cfg(feature = "mermaid")]
+impl<'a> crate::GraphWalk<'a> for MermaidIds {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = ();
+
+    fn nodes(&'a self) -> crate::Nodes<'a, Node> {
+        vec![0].into()
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, (Node, Node)> {
+        vec![(0, 1), (2, 0)].into()
+    }
+
+    fn source(&'a self, edge: &(Node, Node)) -> Node {
+        edge.0
+    }
+
+    fn target(&'a self, edge: &(Node, Node)) -> Node {
+        edge.1
+    }
+}
+
+#[test]
+#[cfg(feature = "mermaid")]
+fn mermaid_ids() {
+    let mut writer = Vec::new();
+    crate::render_mermaid(&MermaidIds, &mut writer).unwrap();
+
+    assert_eq!(
+        String::from_utf8(writer).unwrap(),
+        r#"flowchart TD
+    n0["N0"]
+    n1["N1"]
+    n0 --> n1
+    n1 --> n0
+"#
+    );
+}
+
+labelled_graph!(TableGraph {
+    fn node_label(&'a self, n: &Node) -> crate::Result<crate::label::Text<'a>> {
+        let table = crate::label::Table::new()
+            .row()
+            .cell(crate::label::Cell::new(format!("N{n}")).colspan(2))
+            .row()
+            .cell(crate::label::Cell::new("in").port("in"))
+            .cell(
+                crate::label::Cell::new("<out>")
+                    .port("out")
+                    .rowspan(1)
+                    .bgcolor("#ff0000"),
+            );
+
+        Ok(table.into())
+    }
+
+    fn node_shape(&'a self, _n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("plaintext".into()))
+    }
+});
+
+#[test]
+fn table_label() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = TableGraph(LabelledGraph::new("table", labels, vec![], vec![], None));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r##"digraph table {
+    N0[label=<<table><tr><td colspan="2">N0</td></tr><tr><td port="in">in</td><td port="out" rowspan="1" bgcolor="#ff0000">&lt;out&gt;</td></tr></table>>][shape="plaintext"];
+}
+"##
+    );
+}
+
+labelled_graph!(IntWeightGraph {
+    fn edge_weight(&'a self, _e: &&'a Edge) -> Option<f64> {
+        Some(2.5)
+    }
+
+    fn edge_weight_int(&'a self, e: &&'a Edge) -> Option<i64> {
+        if e.to == 1 {
+            Some(3)
+        } else {
+            None
+        }
+    }
+});
+
+#[test]
+fn edge_weight_int() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(3);
+    let g = IntWeightGraph(LabelledGraph::new(
+        "weights",
+        labels,
+        vec![
+            edge(0, 1, "int", crate::Style::None, None),
+            edge(0, 2, "float", crate::Style::None, None),
+        ],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph weights {
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N0 -> N1[label="int"][weight=3];
+    N0 -> N2[label="float"][weight=2.5];
+}
+"#
+    );
+}
+
+labelled_graph!(RecordGraph {
+    fn node_label(&'a self, n: &Node) -> crate::Result<crate::label::Text<'a>> {
+        let record = crate::label::Record::new()
+            .port_field("f0", format!("N{n}"))
+            .group(
+                crate::label::Record::new()
+                    .port_field("f1", "{top}")
+                    .field("a b"),
+            );
+
+        Ok(record.into())
+    }
+
+    fn node_shape(&'a self, _n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("record".into()))
+    }
+
+    fn edge_source_port(
+        &'a self,
+        _e: &&'a Edge,
+    ) -> Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)> {
+        Some(("f1".into(), None))
+    }
+});
+
+#[test]
+fn record_label() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = RecordGraph(LabelledGraph::new(
+        "record",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph record {
+    N0[label="<f0> N0|{<f1> \{top\}|a\ b}"][shape="record"];
+    N1[label="<f0> N1|{<f1> \{top\}|a\ b}"][shape="record"];
+    N0:f1 -> N1[label="E"];
+}
+"#
+    );
+}
+
+#[test]
+fn long_id() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("a_very_long_graph_id", labels, vec![], vec![], None);
+
+    let mut writer = Vec::new();
+    let warnings =
+        crate::render_with_warnings(&g, &mut writer, &[crate::render::Option::MaxIdLength(8)])
+            .unwrap();
+    assert_eq!(warnings, vec![]);
+
+    let warnings = crate::render_with_warnings(
+        &g,
+        &mut writer,
+        &[
+            crate::render::Option::Validate,
+            crate::render::Option::MaxIdLength(8),
+        ],
+    )
+    .unwrap();
+    assert_eq!(
+        warnings,
+        vec![crate::Warning::LongId("a_very_long_graph_id".to_string())]
+    );
+}
+
+labelled_graph!(JustifiedRecordGraph {
+    fn node_label(&'a self, _n: &Node) -> crate::Result<crate::label::Text<'a>> {
+        let record = crate::label::Record::new()
+            .port_field("f0", "title")
+            .left_justified_field("first line\nsecond \\l")
+            .field("a\nb");
+
+        Ok(record.into())
+    }
+
+    fn node_shape(&'a self, _n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("record".into()))
+    }
+});
+
+#[test]
+fn record_left_justified_field() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = JustifiedRecordGraph(LabelledGraph::new(
+        "justified",
+        labels,
+        vec![],
+        vec![],
+        None,
+    ));
+
+    // Only the `\l` ending each line justifies it, backslashes of the
+    // text are escaped and newlines of a plain field stay centered.
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph justified {
+    N0[label="<f0> title|first\ line\lsecond\ \\l\l|a\nb"][shape="record"];
+}
+"#
+    );
+}
+
+#[test]
+fn dot_arrow() {
+    let arrow = crate::Arrow::from_arrow(crate::arrow::Shape::dot());
+
+    assert_eq!(arrow.to_string(), "dot");
+}
+
+#[test]
+fn render_with_progress() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(3);
+    let g = LabelledGraph::new(
+        "progress",
+        labels,
+        vec![
+            edge(0, 1, "A", crate::Style::None, None),
+            edge(1, 2, "B", crate::Style::None, None),
+        ],
+        vec![vec![0, 1]],
+        None,
+    );
+
+    let mut calls = Vec::new();
+    crate::render_with_progress(&g, &mut Vec::new(), &[], |progress| calls.push(progress)).unwrap();
+
+    assert_eq!(calls.len(), 5);
+    assert_eq!(calls.last(), Some(&crate::Progress { nodes: 3, edges: 2 }));
+}
+
+#[test]
+fn pad() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("pad", labels, vec![], vec![], None);
+
+    assert_eq!(
+        test_input_opts(&g, &[crate::render::Option::Pad(crate::Float(0.5))]).unwrap(),
+        r#"digraph pad {
+    graph[pad=0.5];
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+#[test]
+fn float_option_eq() {
+    let nan = crate::render::Option::Pad(crate::Float(f64::NAN));
+
+    assert_eq!(nan, nan.clone());
+    assert_ne!(
+        crate::render::Option::Pad(crate::Float(0.)),
+        crate::render::Option::Pad(crate::Float(-0.))
+    );
+    assert_eq!(
+        crate::RankSep::Inches(0.5.into()),
+        crate::RankSep::Inches(crate::Float(0.5))
+    );
+}
+
+#[test]
+fn non_finite_float_options() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("non_finite", labels, vec![], vec![], None);
+    let nan = crate::Float(f64::NAN);
+    let inf = crate::Float(f64::INFINITY);
+
+    assert_eq!(
+        test_input_opts(
+            &g,
+            &[
+                crate::render::Option::Pad(nan),
+                crate::render::Option::Sep(inf),
+                crate::render::Option::Esep(nan),
+                crate::render::Option::OverlapScaling(nan),
+                crate::render::Option::Nslimit(inf),
+                crate::render::Option::Mclimit(nan),
+                crate::render::Option::Size(crate::Float(7.5), nan),
+                crate::render::Option::RankSep(crate::RankSep::InchesEqually(inf)),
+            ]
+        )
+        .unwrap(),
+        r#"digraph non_finite {
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+#[test]
+fn arrow_from_shapes() {
+    let mut arrow = crate::Arrow::from_shapes([crate::arrow::Shape::Box(
+        crate::Fill::Open,
+        crate::Side::Both,
+    )]);
+    arrow.push(crate::arrow::Shape::dot());
+
+    assert_eq!(arrow.to_string(), "oboxdot");
+    assert!(
+        arrow
+            == crate::Arrow::from([
+                crate::arrow::Shape::Box(crate::Fill::Open, crate::Side::Both),
+                crate::arrow::Shape::dot(),
+            ])
+    );
+}
+
+#[test]
+fn arrow_from_str() {
+    for spec in [
+        "onormal",
+        "invdot",
+        "oboxdot",
+        "lteeoldiamond",
+        "normalnone",
+        "nonenormal",
+    ] {
+        let arrow: crate::Arrow = spec.parse().unwrap();
+
+        assert_eq!(arrow.to_string(), spec);
+    }
+
+    assert!(
+        "invdot".parse::<crate::Arrow>().unwrap()
+            == crate::Arrow::from([crate::arrow::Shape::inv(), crate::arrow::Shape::dot()])
+    );
+
+    for spec in [
+        "",
+        "otee",
+        "ldot",
+        "square",
+        "normalnormalnormalnormalnormal",
+    ] {
+        assert!(matches!(
+            spec.parse::<crate::Arrow>(),
+            Err(crate::Error::InvalidArrow(_))
+        ));
+    }
+}
+
+struct AutoQuotedGraph(&'static str);
+
+impl<'a> crate::Labeller<'a> for AutoQuotedGraph {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = ();
+
+    fn graph_id(&'a self) -> crate::Result<crate::Id<'a>> {
+        Ok(crate::Id::auto_quoted(self.0))
+    }
+
+    fn node_id(&'a self, n: &Node) -> crate::Result<crate::Id<'a>> {
+        id_name(n)
+    }
+}
+
+impl<'a> crate::GraphWalk<'a> for AutoQuotedGraph {
+    type Node = Node;
+    type Edge = (Node, Node);
+    type Subgraph = ();
+
+    fn nodes(&'a self) -> crate::Nodes<'a, Node> {
+        vec![0].into()
+    }
+
+    fn edges(&'a self) -> crate::Edges<'a, (Node, Node)> {
+        vec![].into()
+    }
+
+    fn source(&'a self, edge: &(Node, Node)) -> Node {
+        edge.0
+    }
+
+    fn target(&'a self, edge: &(Node, Node)) -> Node {
+        edge.1
+    }
+}
+
+#[test]
+fn auto_quoted_graph_id() {
+    assert_eq!(crate::Id::auto_quoted("report").to_string(), "report");
+    assert_eq!(crate::Id::auto_quoted("-2.5").to_string(), "-2.5");
+
+    assert_eq!(
+        test_input_opts(&AutoQuotedGraph("2024-report"), &[]).unwrap(),
+        r#"digraph "2024-report" {
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+#[test]
+fn arrow_shape_names() {
+    let names = crate::arrow::Shape::all_names();
+
+    assert!(names.contains(&"crow"));
+    assert_eq!(names.len(), 11);
+}
+
+#[test]
+fn arrow_try_new() {
+    let arrow = crate::Arrow::try_new(vec![
+        crate::arrow::Shape::tee(),
+        crate::arrow::Shape::dot(),
+        crate::arrow::Shape::none(),
+    ])
+    .unwrap();
+    assert_eq!(arrow.to_string(), "teedotnone");
+
+    assert!(matches!(
+        crate::Arrow::try_new(vec![crate::arrow::Shape::dot(); 5]),
+        Err(crate::Error::InvalidArrow(arrow)) if arrow == "dotdotdotdotdot"
+    ));
+    assert!(crate::Arrow::try_new(vec![
+        crate::arrow::Shape::spacer(),
+        crate::arrow::Shape::normal(),
+    ])
+    .is_ok());
+}
+
+#[test]
+fn ranksep() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("ranksep", labels, vec![], vec![], None);
+
+    for (ranksep, expected) in [
+        (crate::RankSep::Inches(crate::Float(0.5)), r#""0.5""#),
+        (crate::RankSep::Equally, r#""equally""#),
+        (
+            crate::RankSep::InchesEqually(crate::Float(1.2)),
+            r#""1.2 equally""#,
+        ),
+    ] {
+        assert_eq!(
+            test_input_opts(&g, &[crate::render::Option::RankSep(ranksep)]).unwrap(),
+            format!(
+                r#"digraph ranksep {{
+    graph[ranksep={expected}];
+    N0[label="N0"];
+}}
+"#
+            )
+        );
+    }
+}
+
+labelled_graph!(DirGraph {
+    fn edge_dir(&'a self, e: &&'a Edge) -> Option<crate::Direction> {
+        match e.to {
+            1 => Some(crate::Direction::None),
+            2 => Some(crate::Direction::Back),
+            _ => None,
+        }
+    }
+});
+
+#[test]
+fn edge_dir() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(4);
+    let g = DirGraph(LabelledGraph::new(
+        "dir",
+        labels,
+        vec![
+            edge(0, 1, "none", crate::Style::None, None),
+            edge(0, 2, "back", crate::Style::None, None),
+            edge_with_arrows(
+                0,
+                3,
+                "inferred",
+                crate::Style::None,
+                crate::Arrow::normal(),
+                crate::Arrow::default(),
+                None,
+            ),
+        ],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph dir {
+    N0[label="N0"];
+    N1[label="N1"];
+    N2[label="N2"];
+    N3[label="N3"];
+    N0 -> N1[label="none"][dir="none"];
+    N0 -> N2[label="back"][dir="back"];
+    N0 -> N3[label="inferred"][dir="both" arrowtail="normal"];
+}
+"#
+    );
+}
+
+labelled_graph!(RecordPortsGraph {
+    fn node_label(&'a self, n: &Node) -> crate::Result<crate::label::Text<'a>> {
+        let record = crate::label::Record::new()
+            .port_field("title", format!("node {n}"))
+            .group(
+                crate::label::Record::new()
+                    .port_field("in", "in")
+                    .port_field("out", "out"),
+            );
+
+        Ok(record.into())
+    }
+
+    fn node_shape(&'a self, _n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("record".into()))
+    }
+
+    fn edge_source_port(
+        &'a self,
+        _e: &&'a Edge,
+    ) -> Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)> {
+        Some(("out".into(), Some(crate::Compass::E)))
+    }
+
+    fn edge_target_port(
+        &'a self,
+        e: &&'a Edge,
+    ) -> Option<(std::borrow::Cow<'a, str>, Option<crate::Compass>)> {
+        let port = if e.label == "title" { "title" } else { "in" };
+
+        Some((port.into(), None))
+    }
+});
+
+#[test]
+fn record_ports() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(2);
+    let g = RecordPortsGraph(LabelledGraph::new(
+        "records",
+        labels,
+        vec![
+            edge(0, 1, "data", crate::Style::None, None),
+            edge(1, 0, "title", crate::Style::None, None),
+        ],
+        vec![],
+        None,
+    ));
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph records {
+    N0[label="<title> node\ 0|{<in> in|<out> out}"][shape="record"];
+    N1[label="<title> node\ 1|{<in> in|<out> out}"][shape="record"];
+    N0:out:e -> N1:in[label="data"];
+    N1:out:e -> N0:title[label="title"];
+}
+"#
+    );
+}
+
+labelled_graph!(FilledGraph {
+    fn node_styles(&'a self, n: &Node) -> crate::StyleSet {
+        match n {
+            2 => crate::StyleSet::default(),
+            _ => [crate::Style::Filled].into_iter().collect(),
+        }
+    }
+
+    fn node_color(&'a self, _n: &Node) -> Option<crate::label::Text<'a>> {
+        Some(LabelStr("red".into()))
+    }
+
+    fn node_fillcolor(&'a self, n: &Node) -> Option<crate::label::Text<'a>> {
+        if *n == 1 {
+            Some(LabelStr("blue".into()))
+        } else {
+            None
+        }
+    }
+});
+
+#[test]
+fn fill_from_color() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(3);
+    let g = FilledGraph(LabelledGraph::new("filled", labels, vec![], vec![], None));
+
+    assert_eq!(
+        test_input_opts(&g, &[crate::render::Option::FillFromColor]).unwrap(),
+        r#"digraph filled {
+    N0[label="N0"][style="filled"][color="red"][fillcolor="red"];
+    N1[label="N1"][style="filled"][color="red"][fillcolor="blue"];
+    N2[label="N2"][color="red"];
+}
+"#
+    );
+
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph filled {
+    N0[label="N0"][style="filled"][color="red"];
+    N1[label="N1"][style="filled"][color="red"][fillcolor="blue"];
+    N2[label="N2"][color="red"];
+}
+"#
+    );
+}
+
+#[test]
+fn empty_graph_is_empty() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(0);
+    let g = LabelledGraph::new("empty_graph", labels, vec![], vec![], None);
+
+    assert!(crate::GraphWalk::is_empty(&g));
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph empty_graph {
+}
+"#
+    );
+    assert_eq!(
+        test_input_opts(&g, &[crate::render::Option::Pad(crate::Float(0.5))]).unwrap(),
+        r#"digraph empty_graph {
+    graph[pad=0.5];
+}
+"#
+    );
+
+    // Edges and subgraphs are still rendered without nodes.
+    let labels: Trivial = NodeLabels::UnlabelledNodes(0);
+    let g = LabelledGraph::new(
+        "implicit_nodes",
+        labels,
+        vec![edge(0, 1, "E", crate::Style::None, None)],
+        vec![vec![]],
+        None,
+    );
+
+    assert!(crate::GraphWalk::is_empty(&g));
+    assert_eq!(
+        test_input_opts(&g, &[]).unwrap(),
+        r#"digraph implicit_nodes {
+    subgraph cluster_0 {
+        label="";
+    }
+    N0 -> N1[label="E"];
+}
+"#
+    );
+}
+
+#[test]
+fn rankdir() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("rankdir", labels, vec![], vec![], None);
+
+    assert_eq!(
+        test_input_opts(&g, &[crate::render::Option::Rankdir(crate::Rankdir::LR)]).unwrap(),
+        r#"digraph rankdir {
+    graph[rankdir=LR];
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+#[test]
+fn size_ratio_dpi() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("size", labels, vec![], vec![], None);
+
+    let r = test_input_opts(
+        &g,
+        &[
+            crate::render::Option::Size(crate::Float(7.5), crate::Float(10.)),
+            crate::render::Option::Ratio("fill".to_string()),
+            crate::render::Option::Dpi(300),
+        ],
+    );
+
+    assert_eq!(
+        r.unwrap(),
+        r#"digraph size {
+    graph[size="7.5,10" ratio="fill" dpi=300];
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+#[test]
+fn packmode_array() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("pack", labels, vec![], vec![], None);
+
+    assert_eq!(
+        test_input_opts(
+            &g,
+            &[crate::render::Option::Packmode(crate::Packmode::Array)]
+        )
+        .unwrap(),
+        r#"digraph pack {
+    graph[packmode="array"];
+    N0[label="N0"];
+}
+"#
+    );
+}
+
+#[test]
+fn bgcolor() {
+    let labels: Trivial = NodeLabels::UnlabelledNodes(1);
+    let g = LabelledGraph::new("bgcolor", labels, vec![], vec![], None);
+    let lightgray = crate::render::Option::Bgcolor(LabelStr("lightgray".into()));
+
+    assert_eq!(
+        test_input_opts(&g, std::slice::from_ref(&lightgray)).unwrap(),
+        r#"digraph bgcolor {
+    graph[bgcolor="lightgray"];
+    N0[label="N0"];
+}
+"#
+    );
+
+    assert_eq!(
+        test_input_opts(&g, &[crate::render::Option::DarkTheme, lightgray.clone()]).unwrap(),
+        r#"digraph bgcolor {
+    graph[bgcolor="lightgray" fontcolor="white"];
+    node[color="white" fontcolor="white"];
+    edge[color="white" fontcolor="white"];
+    N0[label="N0"];
+}
+"#
+    );
+
+    assert_eq!(
+        test_input_opts(&g, &[lightgray, crate::render::Option::DarkTheme]).unwrap(),
+        r#"digraph bgcolor {
+    graph[bgcolor="black" fontcolor="white"];
+    node[color="white" fontcolor="white"];
+    edge[color="white" fontcolor="white"];
+    N0[label="N0"];
+}
+"#
+    );
+}
@@ -0,0 +1,43 @@
+/// Callbacks invoked by [`walk`] for each element of a graph.
+///
+/// All methods default to doing nothing, implement only the ones you
+/// need.
+pub trait Visitor<N, E, S> {
+    fn visit_node(&mut self, _node: &N) {}
+    fn visit_edge(&mut self, _edge: &E) {}
+    fn visit_subgraph(&mut self, _subgraph: &S) {}
+}
+
+/// Traverses `g` in rendering order (subgraphs, nodes then edges),
+/// calling `visitor` for each element, without rendering anything. The
+/// `GraphWalk::subgraph_edges` are visited after the other edges.
+pub fn walk<'a, G, V>(g: &'a G, visitor: &mut V)
+where
+    G: crate::GraphWalk<'a>,
+    V: Visitor<G::Node, G::Edge, G::Subgraph>,
+{
+    walk_subgraphs(g, &g.subgraphs(), visitor);
+
+    for n in g.nodes().iter() {
+        visitor.visit_node(n);
+    }
+
+    for e in crate::graph_walk::all_edges(g) {
+        visitor.visit_edge(&e);
+    }
+}
+
+/// Visits `subgraphs`, each followed by its nested subgraphs.
+fn walk_subgraphs<'a, G, V>(
+    g: &'a G,
+    subgraphs: &crate::Subgraphs<'a, G::Subgraph>,
+    visitor: &mut V,
+) where
+    G: crate::GraphWalk<'a>,
+    V: Visitor<G::Node, G::Edge, G::Subgraph>,
+{
+    for s in subgraphs.iter() {
+        visitor.visit_subgraph(s);
+        walk_subgraphs(g, &g.subgraph_children(s), visitor);
+    }
+}
@@ -0,0 +1,30 @@
+/// Non-fatal issues found in a graph, reported by
+/// [`render_with_warnings`](crate::render::render_with_warnings).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Warning {
+    /// Several nodes share this id, graphviz merges them.
+    DuplicateId(String),
+    /// A style only meaningful for nodes is used on an edge, graphviz
+    /// ignores it.
+    NodeStyleOnEdge(crate::Style),
+    /// This edge has a `weight` but `constraint=false`, dot ignores the
+    /// weight for ranking. Only reported with `Option::Validate`.
+    WeightWithoutConstraint(String),
+    /// This id is longer than `Option::MaxIdLength`, by default 16384
+    /// bytes, likely a label used as id by mistake. Only reported with
+    /// `Option::Validate`.
+    LongId(String),
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DuplicateId(id) => write!(f, "Duplicate id: {id}"),
+            Self::NodeStyleOnEdge(style) => write!(f, "Node style on edge: {style}"),
+            Self::WeightWithoutConstraint(edge) => {
+                write!(f, "Weight ignored without constraint: {edge}")
+            }
+            Self::LongId(id) => write!(f, "Id too long: {id}"),
+        }
+    }
+}